@@ -1,5 +1,6 @@
 use colored::Colorize;
-use kanalang::Translator;
+use kanalang::{Lang, Translator};
+use kanalang::dict::Dictionary;
 use reqwest::Client;
 use rustyline::error::ReadlineError;
 use rustyline::DefaultEditor;
@@ -84,6 +85,21 @@ fn print_usage() {
         "--api-key <key>".green(),
         "API key for authentication".white()
     );
+    println!(
+        "  {}  {}",
+        "--lang <code>".green(),
+        "Language you chat in: en, pl, es or de (default en)".white()
+    );
+    println!(
+        "  {}  {}",
+        "--dict <path>".green(),
+        "Dictionary file to translate with".white()
+    );
+    println!(
+        "  {}  {}",
+        "--glossary <path>".green(),
+        "Glossary layered over the dictionary (repeatable)".white()
+    );
     println!();
 }
 
@@ -99,6 +115,9 @@ async fn main() {
     let mut endpoint = String::new();
     let mut model = String::new();
     let mut api_key = String::new();
+    let mut lang = Lang::English;
+    let mut dict_path: Option<String> = None;
+    let mut glossaries: Vec<String> = Vec::new();
 
     let mut i = 1;
     while i < args.len() {
@@ -115,6 +134,24 @@ async fn main() {
                 api_key = args[i + 1].clone();
                 i += 2;
             }
+            "--lang" if i + 1 < args.len() => {
+                match args[i + 1].parse() {
+                    Ok(parsed) => lang = parsed,
+                    Err(e) => {
+                        eprintln!("{} {}", "error:".red(), e);
+                        return;
+                    }
+                }
+                i += 2;
+            }
+            "--dict" if i + 1 < args.len() => {
+                dict_path = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--glossary" if i + 1 < args.len() => {
+                glossaries.push(args[i + 1].clone());
+                i += 2;
+            }
             _ => {
                 eprintln!("Unknown option: {}", args[i]);
                 print_usage();
@@ -132,7 +169,40 @@ async fn main() {
         return;
     }
 
-    let translator = Translator::new();
+    let mut dict = match &dict_path {
+        Some(path) => match Dictionary::from_path(path) {
+            Ok(dict) => dict.with_lang(lang),
+            Err(e) => {
+                eprintln!("{} cannot load {}: {}", "error:".red(), path, e);
+                return;
+            }
+        },
+        None => Dictionary::builtin(lang),
+    };
+    for path in &glossaries {
+        match Dictionary::from_path(path) {
+            Ok(glossary) => {
+                if let Some(found) = glossary.likely_lang()
+                    && found != dict.lang()
+                {
+                    eprintln!(
+                        "{} {} has {} glosses, but the dictionary is {}",
+                        "warning:".yellow().bold(),
+                        path,
+                        found.name(),
+                        dict.lang().name()
+                    );
+                }
+                dict = dict.overlay(path.as_str(), &glossary)
+            }
+            Err(e) => {
+                eprintln!("{} cannot load {}: {}", "error:".red(), path, e);
+                return;
+            }
+        }
+    }
+
+    let translator = Translator::with_dictionary(dict);
     let client = Client::new();
     let mut messages: Vec<Message> = vec![Message {
         role: "system".to_string(),
@@ -154,7 +224,7 @@ async fn main() {
     );
     println!(
         "{}",
-        "LLM responses will be translated back to English.".dimmed()
+        format!("LLM responses will be translated back to {}.", translator.lang()).dimmed()
     );
     println!();
 
@@ -173,8 +243,12 @@ async fn main() {
 
                 let _ = rl.add_history_entry(input);
 
-                let kana_input = translator.english_to_kana(input);
+                let translation = translator.to_kana_annotated(input);
+                let kana_input = translation.text;
                 println!("{} {}", "[kanalang]".dimmed(), kana_input.dimmed());
+                for note in &translation.notes {
+                    println!("{} {}", "[note]".dimmed(), note.message.dimmed());
+                }
 
                 messages.push(Message {
                     role: "user".to_string(),
@@ -207,12 +281,19 @@ async fn main() {
                                             kana_response.dimmed()
                                         );
 
-                                        let english_response =
-                                            translator.kana_to_english(kana_response);
+                                        let translation =
+                                            translator.from_kana_annotated(kana_response);
+                                        for note in &translation.notes {
+                                            println!(
+                                                "{} {}",
+                                                "[note]".dimmed(),
+                                                note.message.dimmed()
+                                            );
+                                        }
                                         println!(
                                             "{} {}",
                                             "llm>".green().bold(),
-                                            english_response.white()
+                                            translation.text.white()
                                         );
 
                                         messages.push(choice.message.clone());
@@ -0,0 +1,276 @@
+//! Compiles the `data/dictionary*.tsv` files and `data/thesaurus.tsv` into
+//! static perfect-hash tables, so the built-in dictionaries need no work at
+//! runtime.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// One built-in dictionary per language: (module, source). English comes
+/// first; the others may only use its kana.
+const SOURCES: &[(&str, &str)] = &[
+    ("en", "data/dictionary.tsv"),
+    ("pl", "data/dictionary.pl.tsv"),
+    ("es", "data/dictionary.es.tsv"),
+    ("de", "data/dictionary.de.tsv"),
+];
+const THESAURUS: &str = "data/thesaurus.tsv";
+
+/// A word type, its glosses and its example sentences.
+type Sense<'a> = (&'static str, Vec<&'a str>, Vec<&'a str>);
+
+/// Each kana with its senses, in file order.
+type Words<'a> = Vec<(String, Vec<Sense<'a>>)>;
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", THESAURUS);
+    let data: Vec<String> = SOURCES
+        .iter()
+        .map(|(_, source)| {
+            println!("cargo:rerun-if-changed={}", source);
+            fs::read_to_string(source).unwrap_or_else(|_| panic!("cannot read {}", source))
+        })
+        .collect();
+    let mut out = String::new();
+    // The English words and gloss index, which the others are checked against.
+    let mut english: Option<(Words, BTreeMap<String, String>)> = None;
+
+    for (&(module, source), data) in SOURCES.iter().zip(&data) {
+        let words = read_dictionary(source, data);
+
+        if let Some((base, _)) = &english {
+            for (kana, _) in &words {
+                if !kana_exists(base, kana) {
+                    panic!("{}: `{}` is not in {}", source, kana, SOURCES[0].1);
+                }
+            }
+        }
+
+        let (code, gloss_index) = tables(&words);
+        writeln!(
+            out,
+            "pub mod {} {{\n    use super::*;\n\n{}}}",
+            module, code
+        )
+        .unwrap();
+        if english.is_none() {
+            english = Some((words, gloss_index));
+        }
+    }
+
+    let (words, gloss_index) = english.expect("no English dictionary");
+    writeln!(
+        out,
+        "pub static THESAURUS: phf::Map<&'static str, Fallback> = {};",
+        index_map(&thesaurus(&words, &gloss_index))
+    )
+    .unwrap();
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("dictionary.rs");
+    fs::write(dest, out).expect("cannot write generated dictionary");
+}
+
+/// Reads a dictionary file. Lines with the same kana become senses of one
+/// word, in file order.
+fn read_dictionary<'a>(source: &str, data: &'a str) -> Words<'a> {
+    let mut words: Words = Vec::new();
+
+    for (i, line) in data.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fail = |message: &str| -> ! { panic!("{}:{}: {}", source, i + 1, message) };
+
+        let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let (kana, wtype, glosses, examples) = match columns[..] {
+            [kana, wtype, glosses] => (kana, wtype, glosses, ""),
+            [kana, wtype, glosses, examples] => (kana, wtype, glosses, examples),
+            _ => fail("expected 3 or 4 tab-separated columns"),
+        };
+        let Some(wtype) = type_name(wtype) else {
+            fail("unknown word type");
+        };
+        let glosses: Vec<&str> = glosses
+            .split(',')
+            .map(str::trim)
+            .filter(|gloss| !gloss.is_empty())
+            .collect();
+        if kana.is_empty() || glosses.is_empty() {
+            fail("missing kana or gloss");
+        }
+        let examples: Vec<&str> = examples
+            .split('|')
+            .map(str::trim)
+            .filter(|example| !example.is_empty())
+            .collect();
+
+        // Compounds ("tomo tawa") are stored with single spaces.
+        let kana = kana
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        match words.iter_mut().find(|(k, _)| *k == kana) {
+            Some((_, senses)) => senses.push((wtype, glosses, examples)),
+            None => words.push((kana, vec![(wtype, glosses, examples)])),
+        }
+    }
+
+    words
+}
+
+/// The generated tables of one dictionary, and its gloss index.
+fn tables(words: &[(String, Vec<Sense>)]) -> (String, BTreeMap<String, String>) {
+    let mut entries = String::new();
+    let mut kana_index = BTreeMap::new();
+    // Later senses win, as in `Dictionary::from_words`.
+    let mut gloss_index = BTreeMap::new();
+
+    for (w, (kana, senses)) in words.iter().enumerate() {
+        let mut sense_entries = Vec::new();
+        for (s, (wtype, glosses, examples)) in senses.iter().enumerate() {
+            sense_entries.push(format!(
+                "Sense {{ wtype: WordType::{}, glosses: {}, examples: {} }}",
+                wtype,
+                cow_slice(glosses),
+                cow_slice(examples)
+            ));
+            for gloss in glosses.iter().filter(|gloss| !gloss.starts_with('[')) {
+                gloss_index.insert(gloss.to_lowercase(), format!("({}, {})", w, s));
+            }
+        }
+        writeln!(
+            entries,
+            "        Word {{ kana: Cow::Borrowed({:?}), senses: Cow::Borrowed(&[{}]) }},",
+            kana,
+            sense_entries.join(", ")
+        )
+        .unwrap();
+        kana_index.insert(kana.clone(), w.to_string());
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "    pub static WORDS: [Word; {}] = [\n{}    ];",
+        words.len(),
+        entries
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub static KANA_INDEX: phf::Map<&'static str, usize> = {};",
+        index_map(&kana_index)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub static GLOSS_INDEX: phf::Map<&'static str, (usize, usize)> = {};",
+        index_map(&gloss_index)
+    )
+    .unwrap();
+    let longest_phrase = gloss_index
+        .keys()
+        .map(|gloss| gloss.split_whitespace().count())
+        .max()
+        .unwrap_or(1);
+    let longest_compound = words
+        .iter()
+        .map(|(kana, _)| kana.split(' ').count())
+        .max()
+        .unwrap_or(1);
+    writeln!(
+        out,
+        "    pub static TABLES: Tables = Tables {{ words: &WORDS, kana: &KANA_INDEX, \
+         glosses: &GLOSS_INDEX, longest_phrase: {}, longest_compound: {} }};",
+        longest_phrase, longest_compound
+    )
+    .unwrap();
+
+    (out, gloss_index)
+}
+
+fn kana_exists(words: &[(String, Vec<Sense>)], kana: &str) -> bool {
+    words.iter().any(|(k, _)| k == kana)
+}
+
+/// Reads the fallback thesaurus, checking that every Kana word it maps to is
+/// in the English dictionary and that the dictionary doesn't already cover it.
+fn thesaurus(
+    words: &[(String, Vec<Sense>)],
+    gloss_index: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let source = SOURCES[0].1;
+    let data = fs::read_to_string(THESAURUS).expect("cannot read data/thesaurus.tsv");
+    let mut entries = BTreeMap::new();
+
+    for (i, line) in data.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fail = |message: &str| -> ! { panic!("{}:{}: {}", THESAURUS, i + 1, message) };
+
+        let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let [english, kana, wtype, relation] = columns[..] else {
+            fail("expected 4 tab-separated columns");
+        };
+        let Some(wtype) = type_name(wtype) else {
+            fail("unknown word type");
+        };
+        let relation = match relation {
+            "synonym" => "Synonym",
+            "hypernym" => "Hypernym",
+            _ => fail("relation must be `synonym` or `hypernym`"),
+        };
+        if gloss_index.contains_key(&english.to_lowercase()) {
+            fail(&format!("`{}` is already in {}", english, source));
+        }
+        for word in kana.split_whitespace() {
+            if !kana_exists(words, word) {
+                fail(&format!("`{}` is not in {}", word, source));
+            }
+        }
+
+        entries.insert(
+            english.to_lowercase(),
+            format!(
+                "Fallback {{ kana: {:?}, wtype: WordType::{}, relation: Relation::{} }}",
+                kana, wtype, relation
+            ),
+        );
+    }
+
+    entries
+}
+
+/// `Cow::Borrowed(&[Cow::Borrowed("a"), ...])`
+fn cow_slice(items: &[&str]) -> String {
+    let items: Vec<String> = items
+        .iter()
+        .map(|item| format!("Cow::Borrowed({:?})", item))
+        .collect();
+    format!("Cow::Borrowed(&[{}])", items.join(", "))
+}
+
+fn type_name(wtype: &str) -> Option<&'static str> {
+    match wtype {
+        "entity" => Some("Entity"),
+        "action" => Some("Action"),
+        "quality" => Some("Quality"),
+        "particle" => Some("Particle"),
+        "number" => Some("Number"),
+        _ => None,
+    }
+}
+
+fn index_map(index: &BTreeMap<String, String>) -> String {
+    let mut map = phf_codegen::Map::new();
+    for (key, value) in index {
+        map.entry(key.as_str(), value.as_str());
+    }
+    map.build().to_string()
+}
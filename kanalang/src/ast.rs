@@ -0,0 +1,150 @@
+use std::fmt;
+
+/// A parsed Kana sentence:
+/// `[anu] [yu] [se] [context la] subject [li predicate]...`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sentence {
+    /// Offered in place of the sentence before: "mi moku; anu sina lape".
+    pub alternative: bool,
+    pub greeting: bool,
+    pub question: bool,
+    pub context: Option<Clause>,
+    pub clause: Clause,
+}
+
+/// Subjects joined by `en`, followed by zero or more `li` predicates.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Clause {
+    pub subjects: Vec<Phrase>,
+    pub predicates: Vec<Predicate>,
+}
+
+/// A verb phrase with its `e` objects and trailing prepositional phrases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Predicate {
+    pub verb: Phrase,
+    pub objects: Vec<Phrase>,
+    pub prepositions: Vec<Prepositional>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prepositional {
+    pub preposition: String,
+    pub object: Phrase,
+}
+
+/// A head word followed by its modifiers, optionally negated with `ala`,
+/// and any phrases offered in its place with `anu`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Phrase {
+    pub head: String,
+    pub modifiers: Vec<Modifier>,
+    pub negated: bool,
+    pub alternatives: Vec<Phrase>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Modifier {
+    Word(String),
+    Pi(Phrase),
+}
+
+impl Phrase {
+    pub fn new(head: impl Into<String>) -> Self {
+        Phrase {
+            head: head.into(),
+            modifiers: Vec::new(),
+            negated: false,
+            alternatives: Vec::new(),
+        }
+    }
+
+    /// True for a bare `mi` or `sina`, which take no `li`.
+    pub fn is_bare_mi_sina(&self) -> bool {
+        self.modifiers.is_empty()
+            && !self.negated
+            && self.alternatives.is_empty()
+            && (self.head == "mi" || self.head == "sina")
+    }
+}
+
+impl fmt::Display for Phrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A compound head is negated after its first word, the one `ala`
+        // belongs to: "pilin pona" → `pilin ala pona`.
+        match self.head.split_once(' ') {
+            Some((first, rest)) if self.negated => write!(f, "{} ala {}", first, rest)?,
+            _ => write!(f, "{}", self.head)?,
+        }
+        for modifier in &self.modifiers {
+            match modifier {
+                Modifier::Word(word) => write!(f, " {}", word)?,
+                Modifier::Pi(phrase) => write!(f, " pi {}", phrase)?,
+            }
+        }
+        if self.negated && !self.head.contains(' ') {
+            write!(f, " ala")?;
+        }
+        for alternative in &self.alternatives {
+            write!(f, " anu {}", alternative)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.verb)?;
+        for object in &self.objects {
+            write!(f, " e {}", object)?;
+        }
+        for prep in &self.prepositions {
+            write!(f, " {} {}", prep.preposition, prep.object)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subjects: Vec<String> = self.subjects.iter().map(|s| s.to_string()).collect();
+        write!(f, "{}", subjects.join(" en "))?;
+
+        // With no subject ("moku ala") the first predicate stands alone.
+        let skip_li = self.subjects.is_empty()
+            || (self.subjects.len() == 1 && self.subjects[0].is_bare_mi_sina());
+        for (i, predicate) in self.predicates.iter().enumerate() {
+            if i > 0 || !skip_li {
+                write!(f, " li")?;
+            }
+            if i > 0 || !self.subjects.is_empty() {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", predicate)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Sentence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.alternative {
+            parts.push("anu".to_string());
+        }
+        if self.greeting {
+            parts.push("yu".to_string());
+        }
+        if self.question {
+            parts.push("se".to_string());
+        }
+        if let Some(context) = &self.context {
+            parts.push(format!("{} la", context));
+        }
+        let clause = self.clause.to_string();
+        if !clause.is_empty() {
+            parts.push(clause);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
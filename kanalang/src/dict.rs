@@ -1,6 +1,60 @@
+//! Kana vocabulary.
+//!
+//! Dictionaries are plain text, one sense per line, with three or four
+//! tab-separated columns:
+//!
+//! ```text
+//! # kana    type      glosses          examples
+//! moku      action    eat, consume     mi moku e pan | jan li moku
+//! moku      entity    food, meal
+//! ```
+//!
+//! The optional last column holds example Kana sentences separated by `|`.
+//! `type` is one of `entity`, `action`, `quality`, `particle` or `number`.
+//! Lines with the same kana are senses of one word; the first is its default.
+//! Glosses are separated by commas; the first gloss of a sense is used when
+//! translating from Kana. A gloss of several words ("thank you") is a
+//! phrase, matched before the words on their own. Likewise a kana of several
+//! words (`tomo tawa`, "car") is a compound, recognised before its parts.
+//! Blank lines and lines starting with `#` are ignored.
+//!
+//! Dictionaries can be layered: [`Dictionary::overlay`] adds the words of a
+//! glossary on top, merging into senses of the same kana and type.
+//! Each dictionary glosses Kana in one [`Lang`]. The built-in vocabularies
+//! live in `data/dictionary.tsv` (English) and `data/dictionary.<code>.tsv`
+//! and are compiled into static perfect-hash tables by `build.rs`, together
+//! with `data/thesaurus.tsv`, which maps common English words missing from
+//! the dictionary to the closest Kana concept (dog → kala).
+
+use crate::lang::Lang;
+use crate::lint::Severity;
+use crate::morph::{self, Lemma, Number, Tense};
+use crate::parser::MARKERS;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::str::FromStr;
+
+mod builtin {
+    use super::{Fallback, Relation, Sense, Word, WordType};
+    use std::borrow::Cow;
+
+    /// The generated tables of one language.
+    pub struct Tables {
+        pub words: &'static [Word],
+        pub kana: &'static phf::Map<&'static str, usize>,
+        pub glosses: &'static phf::Map<&'static str, (usize, usize)>,
+        pub longest_phrase: usize,
+        pub longest_compound: usize,
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/dictionary.rs"));
+}
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WordType {
     Entity,
     Action,
@@ -9,633 +63,1022 @@ pub enum WordType {
     Number,
 }
 
-#[derive(Clone)]
+impl WordType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WordType::Entity => "entity",
+            WordType::Action => "action",
+            WordType::Quality => "quality",
+            WordType::Particle => "particle",
+            WordType::Number => "number",
+        }
+    }
+}
+
+impl fmt::Display for WordType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for WordType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "entity" => Ok(WordType::Entity),
+            "action" => Ok(WordType::Action),
+            "quality" => Ok(WordType::Quality),
+            "particle" => Ok(WordType::Particle),
+            "number" => Ok(WordType::Number),
+            other => Err(format!("unknown word type `{}`", other)),
+        }
+    }
+}
+
+/// A dictionary entry. Built-in words borrow static data; loaded words own theirs.
+#[derive(Debug, Clone)]
 pub struct Word {
+    pub kana: Cow<'static, str>,
+    /// At least one sense; the first is the default.
+    pub senses: Cow<'static, [Sense]>,
+}
+
+/// One part of speech of a word, with its glosses in the dictionary's language.
+#[derive(Debug, Clone)]
+pub struct Sense {
+    pub wtype: WordType,
+    pub glosses: Cow<'static, [Cow<'static, str>]>,
+    /// Kana sentences using the word in this sense.
+    pub examples: Cow<'static, [Cow<'static, str>]>,
+}
+
+impl Word {
+    pub fn primary(&self) -> &Sense {
+        &self.senses[0]
+    }
+
+    /// The first sense of any of `prefer` (tried in order), else the default sense.
+    pub fn sense_for(&self, prefer: &[WordType]) -> &Sense {
+        prefer
+            .iter()
+            .find_map(|wtype| self.senses.iter().find(|s| s.wtype == *wtype))
+            .unwrap_or_else(|| self.primary())
+    }
+}
+
+impl Sense {
+    /// The gloss used when translating from Kana; `None` for grammatical
+    /// particles whose only glosses are `[placeholders]`.
+    pub fn gloss(&self) -> Option<&str> {
+        self.glosses
+            .first()
+            .map(|g| g.as_ref())
+            .filter(|g| !g.starts_with('['))
+    }
+}
+
+/// How a thesaurus entry relates to the word it stands in for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Synonym,
+    /// A broader concept: "dog" becomes `kala` (animal).
+    Hypernym,
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Relation::Synonym => write!(f, "synonym"),
+            Relation::Hypernym => write!(f, "broader term"),
+        }
+    }
+}
+
+/// A thesaurus entry: Kana for an English word the dictionary lacks.
+#[derive(Debug, Clone, Copy)]
+pub struct Fallback {
     pub kana: &'static str,
-    pub english: Vec<&'static str>,
     pub wtype: WordType,
+    pub relation: Relation,
+}
+
+/// The result of looking up a word of the dictionary's language.
+#[derive(Debug, Clone)]
+pub struct Lookup<'a> {
+    /// One or more Kana words.
+    pub kana: &'a str,
+    pub wtype: WordType,
+    /// The form that matched, with the tense and number stripped from it.
+    pub lemma: Lemma,
+    /// Set when the word came from the thesaurus rather than the dictionary.
+    pub relation: Option<Relation>,
+    /// The dictionary layer the entry came from, or `thesaurus`.
+    pub layer: &'a str,
+}
+
+#[derive(Debug)]
+pub enum DictError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for DictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictError::Io(err) => write!(f, "{}", err),
+            DictError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for DictError {}
+
+impl From<io::Error> for DictError {
+    fn from(err: io::Error) -> Self {
+        DictError::Io(err)
+    }
 }
 
 #[derive(Clone)]
 pub struct Dictionary {
-    pub en_to_kana: HashMap<String, &'static str>,
-    pub kana_to_en: HashMap<String, Vec<&'static str>>,
-    pub word_types: HashMap<String, WordType>,
+    words: Cow<'static, [Word]>,
+    index: Index,
+    /// Layer names, base first.
+    layers: Vec<String>,
+    /// The layer of each sense of each word in `words`; empty while there
+    /// is only one layer.
+    origins: Vec<Vec<usize>>,
+    lang: Lang,
+}
+
+/// Maps kana to positions in `Dictionary::words`, and glosses to
+/// (word, sense) positions.
+#[derive(Clone)]
+enum Index {
+    /// The perfect-hash tables generated by `build.rs`.
+    Builtin(&'static builtin::Tables),
+    Loaded {
+        kana: HashMap<String, usize>,
+        glosses: HashMap<String, (usize, usize)>,
+        /// Words in the longest phrase.
+        longest_phrase: usize,
+        /// Words in the longest kana compound.
+        longest_compound: usize,
+    },
 }
 
 impl Dictionary {
+    /// The built-in English dictionary.
     pub fn new() -> Self {
-        let words: Vec<Word> = vec![
-            Word {
-                kana: "mi",
-                english: vec!["i", "me", "we", "us"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "sina",
-                english: vec!["you"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "ona",
-                english: vec!["he", "she", "it", "they"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "jan",
-                english: vec!["person", "human", "people"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kala",
-                english: vec!["fish", "animal", "creature"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kasi",
-                english: vec!["plant", "tree", "nature", "vegetation"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "pona",
-                english: vec!["good", "simple", "positive", "correct"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "ike",
-                english: vec!["bad", "wrong", "negative", "complex"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "suli",
-                english: vec!["big", "important", "long", "tall"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "lili",
-                english: vec!["small", "little", "short", "young"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "wawa",
-                english: vec!["strong", "powerful", "energy"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "mute",
-                english: vec!["many", "much", "very", "a lot"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "sona",
-                english: vec!["know", "knowledge", "wisdom", "understand"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "wile",
-                english: vec!["want", "need", "desire", "wish"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "ken",
-                english: vec!["can", "able", "possible", "ability"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "lukin",
-                english: vec!["see", "look", "watch", "eye"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "kute",
-                english: vec!["hear", "listen", "ear"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "toki",
-                english: vec!["speak", "say", "talk", "communicate", "language"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "pilin",
-                english: vec!["feel", "think", "believe", "emotion", "heart"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "moku",
-                english: vec!["eat", "food", "consume"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "lape",
-                english: vec!["sleep", "rest", "tired"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "pali",
-                english: vec!["do", "make", "create", "work"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "tawa",
-                english: vec!["go", "move", "to", "towards"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "kama",
-                english: vec!["come", "arrive", "become", "future"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "awen",
-                english: vec!["stay", "remain", "wait", "keep"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "weka",
-                english: vec!["remove", "away", "absent", "gone"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "jo",
-                english: vec!["have", "hold", "possess"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "li",
-                english: vec!["[subject-marker]"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "e",
-                english: vec!["[object-marker]"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "pi",
-                english: vec!["[modifier-marker]"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "la",
-                english: vec!["[context-marker]", "if", "when"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "anu",
-                english: vec!["or"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "en",
-                english: vec!["and"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "se",
-                english: vec!["[question-marker]", "?", "what"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "ala",
-                english: vec!["no", "not", "none", "zero"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "kin",
-                english: vec!["also", "too", "indeed"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "ni",
-                english: vec!["this", "that", "these", "those"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "ale",
-                english: vec!["all", "everything", "universe", "life"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "ijo",
-                english: vec!["thing", "something", "object"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "ma",
-                english: vec!["land", "world", "place", "country", "earth"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "tomo",
-                english: vec!["house", "building", "room", "home"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "ilo",
-                english: vec!["tool", "device", "machine"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "pana",
-                english: vec!["give", "send", "release", "emit"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "olin",
-                english: vec!["love", "affection", "respect", "care"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "nasin",
-                english: vec!["way", "method", "path", "road", "direction"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "tenpo",
-                english: vec!["time", "period", "moment", "situation"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "suno",
-                english: vec!["sun", "day", "light", "brightness"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "mun",
-                english: vec!["moon", "night", "star"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "telo",
-                english: vec!["water", "liquid", "fluid", "drink"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kon",
-                english: vec!["air", "wind", "breath", "spirit"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "seli",
-                english: vec!["fire", "heat", "warm", "cook"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lete",
-                english: vec!["cold", "ice", "cool", "frozen"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "akuta",
-                english: vec!["truth", "real", "honest", "transparent"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "jaki",
-                english: vec!["dirty", "gross", "contaminated"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "sin",
-                english: vec!["new", "fresh", "another", "more"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "pini",
-                english: vec!["done", "finished", "completed", "past", "end"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "kule",
-                english: vec!["color", "colorful", "paint"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "walo",
-                english: vec!["white", "light-colored", "pale"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "pimeja",
-                english: vec!["black", "dark", "darkness"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "loje",
-                english: vec!["red", "reddish"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "laso",
-                english: vec!["blue", "green", "bluish"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "jelo",
-                english: vec!["yellow", "yellowish"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "luka",
-                english: vec!["hand", "arm", "five", "touch"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "noka",
-                english: vec!["foot", "leg", "walk", "bottom"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "monsi",
-                english: vec!["back", "behind", "rear"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "sinpin",
-                english: vec!["front", "face", "wall"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "sewi",
-                english: vec!["up", "above", "high", "sky", "divine"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "anpa",
-                english: vec!["down", "below", "low", "humble"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "insa",
-                english: vec!["inside", "inner", "center", "stomach"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "poka",
-                english: vec!["side", "next-to", "hip", "with"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lon",
-                english: vec!["located-at", "exist", "real", "true"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "tan",
-                english: vec!["from", "because-of", "origin", "cause"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "sama",
-                english: vec!["same", "similar", "equal", "like"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "ante",
-                english: vec!["different", "other", "changed"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "kepeken",
-                english: vec!["use", "with", "using"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "open",
-                english: vec!["begin", "start", "open"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "pan",
-                english: vec!["grain", "bread", "rice", "food"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "esun",
-                english: vec!["trade", "market", "buy", "sell", "shop"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lape",
-                english: vec!["sleep", "rest"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "musi",
-                english: vec!["fun", "play", "entertain", "art"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "uta",
-                english: vec!["mouth", "lips"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "nena",
-                english: vec!["bump", "hill", "mountain", "nose"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "linja",
-                english: vec!["long", "rope", "hair", "line"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "palisa",
-                english: vec!["stick", "rod", "long-object"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lupa",
-                english: vec!["hole", "door", "window", "opening"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lipu",
-                english: vec!["paper", "book", "document", "flat"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kiwen",
-                english: vec!["hard", "solid", "stone", "metal"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "ko",
-                english: vec!["soft", "clay", "semisolid", "paste"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "namako",
-                english: vec!["spice", "extra", "additional", "flavor"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "oko",
-                english: vec!["eye", "vision"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "melome",
-                english: vec!["woman", "female", "feminine"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "mije",
-                english: vec!["man", "male", "masculine"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kasi",
-                english: vec!["plant", "leaf", "herb", "grow"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "sike",
-                english: vec!["circle", "round", "ball", "cycle"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "len",
-                english: vec!["cloth", "clothing", "fabric", "cover"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "unpa",
-                english: vec!["sexual", "marriage", "intimate"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "pakala",
-                english: vec!["break", "damage", "destroy", "mistake"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "selo",
-                english: vec!["outer", "skin", "surface", "boundary"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "leko",
-                english: vec!["square", "block", "corner"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "lanpan",
-                english: vec!["take", "get", "receive", "grab"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "wan",
-                english: vec!["one", "unique", "unite"],
-                wtype: WordType::Number,
-            },
-            Word {
-                kana: "tu",
-                english: vec!["two", "split", "divide"],
-                wtype: WordType::Number,
-            },
-            Word {
-                kana: "mute",
-                english: vec!["many", "several", "much", "quantity"],
-                wtype: WordType::Number,
-            },
-            Word {
-                kana: "nanpa",
-                english: vec!["number", "order", "th"],
-                wtype: WordType::Number,
-            },
-            Word {
-                kana: "lawa",
-                english: vec!["head", "lead", "control", "main", "ruler"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "kipisi",
-                english: vec!["split", "cut", "divide", "slice"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "monsuta",
-                english: vec!["fear", "monster", "danger", "scary"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "tonsili",
-                english: vec!["health", "wellness", "safe"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "meso",
-                english: vec!["medium", "average", "middle"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "jami",
-                english: vec!["tasty", "delicious", "yummy"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "suwi",
-                english: vec!["sweet", "cute", "candy", "sugar"],
-                wtype: WordType::Quality,
-            },
-            Word {
-                kana: "wile",
-                english: vec!["want", "need", "must", "should"],
-                wtype: WordType::Action,
-            },
-            Word {
-                kana: "kijetesantakalu",
-                english: vec!["raccoon", "ferret", "mustelid"],
-                wtype: WordType::Entity,
-            },
-            Word {
-                kana: "yu",
-                english: vec!["hello", "greeting", "hi"],
-                wtype: WordType::Particle,
-            },
-            Word {
-                kana: "poka",
-                english: vec!["friend", "companion", "together"],
-                wtype: WordType::Entity,
-            },
-        ];
+        Self::builtin(Lang::English)
+    }
+
+    /// The built-in dictionary for `lang`.
+    pub fn builtin(lang: Lang) -> Self {
+        let tables = match lang {
+            Lang::English => &builtin::en::TABLES,
+            Lang::Polish => &builtin::pl::TABLES,
+            Lang::Spanish => &builtin::es::TABLES,
+            Lang::German => &builtin::de::TABLES,
+        };
+        Dictionary {
+            words: Cow::Borrowed(tables.words),
+            index: Index::Builtin(tables),
+            layers: vec!["built-in".to_string()],
+            origins: Vec::new(),
+            lang,
+        }
+    }
 
-        let mut en_to_kana = HashMap::new();
-        let mut kana_to_en = HashMap::new();
-        let mut word_types = HashMap::new();
+    /// Reads a dictionary file; its layer is named after the path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, DictError> {
+        let file = File::open(&path)?;
+        let mut dict = Self::from_reader(BufReader::new(file))?;
+        dict.layers = vec![path.as_ref().display().to_string()];
+        Ok(dict)
+    }
+
+    /// Reads a dictionary in the format described in the module docs.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, DictError> {
+        let mut words: Vec<Word> = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let parse_error = |message: String| DictError::Parse {
+                line: i + 1,
+                message,
+            };
+
+            let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+            let (kana, wtype, glosses, examples) = match columns[..] {
+                [kana, wtype, glosses] => (kana, wtype, glosses, ""),
+                [kana, wtype, glosses, examples] => (kana, wtype, glosses, examples),
+                _ => {
+                    return Err(parse_error(format!(
+                        "expected 3 or 4 tab-separated columns, found {}",
+                        columns.len()
+                    )));
+                }
+            };
+            if kana.is_empty() {
+                return Err(parse_error("missing kana".to_string()));
+            }
+            let wtype = wtype.parse().map_err(parse_error)?;
+            let glosses: Vec<Cow<'static, str>> = glosses
+                .split(',')
+                .map(|gloss| gloss.trim().to_string())
+                .filter(|gloss| !gloss.is_empty())
+                .map(Cow::Owned)
+                .collect();
+            if glosses.is_empty() {
+                return Err(parse_error(format!("`{}` has no gloss", kana)));
+            }
+
+            let kana = kana
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase();
+            let examples: Vec<Cow<'static, str>> = examples
+                .split('|')
+                .map(|example| example.trim().to_string())
+                .filter(|example| !example.is_empty())
+                .map(Cow::Owned)
+                .collect();
+            let sense = Sense {
+                wtype,
+                glosses: Cow::Owned(glosses),
+                examples: Cow::Owned(examples),
+            };
+            match words.iter_mut().find(|w| w.kana == kana) {
+                Some(word) => word.senses.to_mut().push(sense),
+                None => words.push(Word {
+                    kana: Cow::Owned(kana),
+                    senses: Cow::Owned(vec![sense]),
+                }),
+            }
+        }
+
+        Ok(Self::from_words(words))
+    }
 
-        for word in &words {
-            word_types.insert(word.kana.to_string(), word.wtype);
-            let meanings: Vec<&str> = word.english.clone();
-            kana_to_en.insert(word.kana.to_string(), meanings.clone());
+    /// Later words override earlier ones with the same kana or gloss. The
+    /// glosses are taken to be English; see [`Dictionary::with_lang`].
+    pub fn from_words(words: Vec<Word>) -> Self {
+        let mut kana = HashMap::new();
+        let mut glosses = HashMap::new();
+        let mut longest_phrase = 1;
+        let mut longest_compound = 1;
 
-            for meaning in &word.english {
-                if !meaning.starts_with('[') {
-                    en_to_kana.insert(meaning.to_lowercase(), word.kana);
+        for (w, word) in words.iter().enumerate() {
+            kana.insert(word.kana.to_string(), w);
+            longest_compound = longest_compound.max(word.kana.split(' ').count());
+            for (s, sense) in word.senses.iter().enumerate() {
+                for meaning in sense.glosses.iter() {
+                    if !meaning.starts_with('[') {
+                        let meaning = meaning.to_lowercase();
+                        longest_phrase = longest_phrase.max(meaning.split_whitespace().count());
+                        glosses.insert(meaning, (w, s));
+                    }
                 }
             }
         }
 
         Dictionary {
-            en_to_kana,
-            kana_to_en,
-            word_types,
+            words: Cow::Owned(words),
+            index: Index::Loaded {
+                kana,
+                glosses,
+                longest_phrase,
+                longest_compound,
+            },
+            layers: vec!["custom".to_string()],
+            origins: Vec::new(),
+            lang: Lang::English,
         }
     }
 
-    pub fn translate_en_word(&self, word: &str) -> Option<&str> {
+    /// Marks the glosses as being in `lang`, for a dictionary read from a file.
+    pub fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    /// The language of the glosses.
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    /// The language whose built-in dictionary knows the most of these
+    /// glosses, or `None` if no language stands out. A glossary file doesn't
+    /// say what language it is in, so this is how a mismatch is caught.
+    pub fn likely_lang(&self) -> Option<Lang> {
+        let glosses: Vec<&str> = self
+            .words
+            .iter()
+            .flat_map(|word| word.senses.iter())
+            .flat_map(|sense| sense.glosses.iter())
+            .filter(|gloss| !gloss.starts_with('['))
+            .map(|gloss| gloss.as_ref())
+            .collect();
+        let mut counts: Vec<(usize, Lang)> = Lang::ALL
+            .iter()
+            .map(|&lang| {
+                let builtin = Dictionary::builtin(lang);
+                let known = glosses
+                    .iter()
+                    .filter(|gloss| builtin.lookup_gloss(gloss).is_some())
+                    .count();
+                (known, lang)
+            })
+            .collect();
+        counts.sort_by_key(|&(known, _)| std::cmp::Reverse(known));
+        match counts[..] {
+            [(best, lang), (next, _), ..] if best > next => Some(lang),
+            _ => None,
+        }
+    }
+
+    /// Layers `overlay` on top of this dictionary as a layer called `name`.
+    /// New words and senses are added. An overlay sense of a type the word
+    /// already has is merged into it: its glosses come first, so it wins
+    /// when translating from Kana, and the old glosses still translate.
+    /// A sense belongs to the last layer that touched it.
+    pub fn overlay(self, name: impl Into<String>, overlay: &Dictionary) -> Dictionary {
+        let layer = self.layers.len();
+        let mut origins = self.origins;
+        origins.resize_with(self.words.len(), Vec::new);
+        for (origin, word) in origins.iter_mut().zip(self.words.iter()) {
+            origin.resize(word.senses.len(), 0);
+        }
+        let mut words = self.words.into_owned();
+
+        for word in overlay.words.iter() {
+            match words.iter().position(|w| w.kana == word.kana) {
+                Some(i) => {
+                    let senses = words[i].senses.to_mut();
+                    for sense in word.senses.iter() {
+                        match senses.iter().position(|s| s.wtype == sense.wtype) {
+                            Some(s) => {
+                                senses[s] = merge_senses(sense, &senses[s]);
+                                origins[i][s] = layer;
+                            }
+                            None => {
+                                senses.push(sense.clone());
+                                origins[i].push(layer);
+                            }
+                        }
+                    }
+                }
+                None => {
+                    words.push(word.clone());
+                    origins.push(vec![layer; word.senses.len()]);
+                }
+            }
+        }
+
+        let mut layers = self.layers;
+        layers.push(name.into());
+        Dictionary {
+            layers,
+            origins,
+            lang: self.lang,
+            ..Self::from_words(words)
+        }
+    }
+
+    /// Layer names, base first.
+    pub fn layers(&self) -> &[String] {
+        &self.layers
+    }
+
+    /// The name of the layer the default sense of `kana` was defined in.
+    pub fn layer_of(&self, kana: &str) -> Option<&str> {
+        self.sense_layer(kana, 0)
+    }
+
+    /// The name of the layer that last defined sense `sense` of `kana`.
+    pub fn sense_layer(&self, kana: &str, sense: usize) -> Option<&str> {
+        let i = self.position(kana)?;
+        let layer = self
+            .origins
+            .get(i)
+            .and_then(|senses| senses.get(sense))
+            .copied()
+            .unwrap_or(0);
+        Some(&self.layers[layer])
+    }
+
+    /// The layer of a sense found by a lookup.
+    fn layer_for(&self, word: &Word, sense: &Sense) -> &str {
+        let index = word
+            .senses
+            .iter()
+            .position(|s| std::ptr::eq(s, sense))
+            .unwrap_or(0);
+        self.sense_layer(&word.kana, index).unwrap_or_default()
+    }
+
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    pub fn entry(&self, kana: &str) -> Option<&Word> {
+        self.words.get(self.position(kana)?)
+    }
+
+    fn position(&self, kana: &str) -> Option<usize> {
+        match &self.index {
+            Index::Builtin(tables) => tables.kana.get(kana).copied(),
+            Index::Loaded { kana: index, .. } => index.get(kana).copied(),
+        }
+    }
+
+    /// The most kana words in any compound.
+    pub fn longest_compound(&self) -> usize {
+        match &self.index {
+            Index::Builtin(tables) => tables.longest_compound,
+            Index::Loaded {
+                longest_compound, ..
+            } => *longest_compound,
+        }
+    }
+
+    /// The longest compound of two or more kana words that `words` starts
+    /// with, and how many words it covers.
+    pub fn lookup_compound(&self, words: &[&str]) -> Option<(usize, &Word)> {
+        (2..=self.longest_compound().min(words.len()))
+            .rev()
+            .find_map(|n| Some((n, self.entry(&words[..n].join(" "))?)))
+    }
+
+    /// Compounds that contain `kana` as one of their words.
+    pub fn compounds_with(&self, kana: &str) -> Vec<&Word> {
+        self.words
+            .iter()
+            .filter(|w| w.kana != kana && w.kana.split(' ').any(|part| part == kana))
+            .collect()
+    }
+
+    /// Senses whose glosses match `query`: exact matches of the query or its
+    /// lemmas first, then glosses containing it.
+    pub fn search(&self, query: &str) -> Vec<(&Word, &Sense)> {
+        let query = query.trim().to_lowercase();
+        let lemmas: Vec<String> = morph::lemmas_in(self.lang, &query)
+            .into_iter()
+            .map(|lemma| lemma.base)
+            .collect();
+        let glosses = |sense: &Sense| -> Vec<String> {
+            sense.glosses.iter().map(|g| g.to_lowercase()).collect()
+        };
+
+        let mut exact = Vec::new();
+        let mut partial = Vec::new();
+        for word in self.words.iter() {
+            for sense in word.senses.iter() {
+                let glosses = glosses(sense);
+                if glosses.iter().any(|g| lemmas.contains(g)) {
+                    exact.push((word, sense));
+                } else if glosses.iter().any(|g| g.contains(&query)) {
+                    partial.push((word, sense));
+                }
+            }
+        }
+        exact.extend(partial);
+        exact
+    }
+
+    /// Every sense of the given type, in dictionary order.
+    pub fn senses_of_type(&self, wtype: WordType) -> Vec<(&Word, &Sense)> {
+        self.words
+            .iter()
+            .flat_map(|word| word.senses.iter().map(move |sense| (word, sense)))
+            .filter(|(_, sense)| sense.wtype == wtype)
+            .collect()
+    }
+
+    pub fn senses(&self, kana: &str) -> Option<&[Sense]> {
+        self.entry(kana).map(|w| w.senses.as_ref())
+    }
+
+    /// The word and the sense a gloss belongs to.
+    pub fn lookup_gloss(&self, word: &str) -> Option<(&Word, &Sense)> {
         let lower = word.to_lowercase();
-        self.en_to_kana.get(&lower).copied()
+        let (w, s) = match &self.index {
+            Index::Builtin(tables) => tables.glosses.get(lower.as_str()).copied(),
+            Index::Loaded { glosses: index, .. } => index.get(&lower).copied(),
+        }?;
+        let word = self.words.get(w)?;
+        Some((word, word.senses.get(s)?))
     }
 
-    pub fn translate_kana_word(&self, word: &str) -> Option<&Vec<&str>> {
-        self.kana_to_en.get(word)
+    /// Like `lookup_gloss`, but also tries the lemmas of an inflected word
+    /// ("ate" → "eat"), returning the lemma that matched. A lemma never
+    /// stands for a pronoun ("shed" is not "she"), and in English it must
+    /// be a verb or a noun, as those are all the suffixes inflect.
+    pub fn lookup_inflected(&self, word: &str) -> Option<(Lemma, &Word, &Sense)> {
+        let rules = self.lang.rules();
+        morph::lemmas_in(self.lang, word)
+            .into_iter()
+            .enumerate()
+            .find_map(|(i, lemma)| {
+                let (word, sense) = self.lookup_gloss(&lemma.base)?;
+                let inflected = i > 0
+                    && (rules.pronoun(&lemma.base).is_some()
+                        || (self.lang == Lang::English
+                            && !matches!(sense.wtype, WordType::Action | WordType::Entity)));
+                (!inflected).then_some((lemma, word, sense))
+            })
     }
 
+    /// Finds Kana for a word: the dictionary first, then the lemmas of an
+    /// inflected word, then (for English) the thesaurus. Thesaurus entries
+    /// are only used if all of their Kana words are in this dictionary.
+    pub fn lookup(&self, word: &str) -> Option<Lookup<'_>> {
+        if let Some((lemma, word, sense)) = self.lookup_inflected(word) {
+            return Some(Lookup {
+                kana: &word.kana,
+                wtype: sense.wtype,
+                lemma,
+                relation: None,
+                layer: self.layer_for(word, sense),
+            });
+        }
+
+        if self.lang != Lang::English {
+            return None;
+        }
+        morph::lemmas(word).into_iter().find_map(|lemma| {
+            let fallback = builtin::THESAURUS.get(lemma.base.as_str())?;
+            if !fallback
+                .kana
+                .split_whitespace()
+                .all(|w| self.entry(w).is_some())
+            {
+                return None;
+            }
+            Some(Lookup {
+                kana: fallback.kana,
+                wtype: fallback.wtype,
+                lemma,
+                relation: Some(fallback.relation),
+                layer: "thesaurus",
+            })
+        })
+    }
+
+    /// The most words in any gloss.
+    pub fn longest_phrase(&self) -> usize {
+        match &self.index {
+            Index::Builtin(tables) => tables.longest_phrase,
+            Index::Loaded { longest_phrase, .. } => *longest_phrase,
+        }
+    }
+
+    /// The longest phrase of two or more words that `words` starts with,
+    /// and how many words it covers.
+    pub fn lookup_phrase(&self, words: &[&str]) -> Option<(usize, Lookup<'_>)> {
+        (2..=self.longest_phrase().min(words.len()))
+            .rev()
+            .find_map(|n| {
+                let phrase = words[..n].join(" ");
+                let (word, sense) = self.lookup_gloss(&phrase)?;
+                Some((
+                    n,
+                    Lookup {
+                        kana: &word.kana,
+                        wtype: sense.wtype,
+                        lemma: Lemma::new(&phrase.to_lowercase(), Tense::Present, Number::Singular),
+                        relation: None,
+                        layer: self.layer_for(word, sense),
+                    },
+                ))
+            })
+    }
+
+    pub fn translate_gloss(&self, word: &str) -> Option<&str> {
+        self.lookup_gloss(word).map(|(w, _)| w.kana.as_ref())
+    }
+
+    #[deprecated(note = "use `translate_gloss`")]
+    pub fn translate_en_word(&self, word: &str) -> Option<&str> {
+        self.translate_gloss(word)
+    }
+
+    /// Glosses of the default sense.
+    pub fn translate_kana_word(&self, word: &str) -> Option<&[Cow<'static, str>]> {
+        self.entry(word).map(|w| w.primary().glosses.as_ref())
+    }
+
+    /// The type of the default sense.
     pub fn get_word_type(&self, word: &str) -> Option<WordType> {
-        self.word_types.get(word).copied()
+        self.entry(word).map(|w| w.primary().wtype)
+    }
+
+    pub fn has_type(&self, word: &str, wtype: WordType) -> bool {
+        self.senses(word)
+            .is_some_and(|senses| senses.iter().any(|s| s.wtype == wtype))
+    }
+}
+
+/// An overlay sense merged into the base sense of the same type: the
+/// overlay's glosses and examples first, then the base's it doesn't repeat.
+fn merge_senses(overlay: &Sense, base: &Sense) -> Sense {
+    let merge = |first: &[Cow<'static, str>], second: &[Cow<'static, str>]| {
+        let mut merged = first.to_vec();
+        for item in second {
+            if !merged.iter().any(|m| m.eq_ignore_ascii_case(item)) {
+                merged.push(item.clone());
+            }
+        }
+        Cow::Owned(merged)
+    };
+    Sense {
+        wtype: overlay.wtype,
+        glosses: merge(&overlay.glosses, &base.glosses),
+        examples: merge(&overlay.examples, &base.examples),
+    }
+}
+
+impl Default for Dictionary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    DuplicateKana,
+    ConflictingGloss,
+    NoRoundTrip,
+    ParticleType,
+    CompoundPart,
+    BadExample,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+impl Dictionary {
+    /// Checks the word list for entries that silently shadow each other or
+    /// that the translator cannot use as intended.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let mut issue = |kind, severity, message: String| {
+            issues.push(Issue {
+                kind,
+                severity,
+                message,
+            })
+        };
+
+        let mut kana_count: HashMap<&str, usize> = HashMap::new();
+        let mut gloss_owners: HashMap<String, Vec<&str>> = HashMap::new();
+        for word in self.words.iter() {
+            *kana_count.entry(&word.kana).or_default() += 1;
+            let glosses = word.senses.iter().flat_map(|s| s.glosses.iter());
+            for gloss in glosses.filter(|g| !g.starts_with('[')) {
+                let owners = gloss_owners.entry(gloss.to_lowercase()).or_default();
+                if !owners.contains(&word.kana.as_ref()) {
+                    owners.push(&word.kana);
+                }
+            }
+        }
+
+        let mut reported = Vec::new();
+        for word in self.words.iter() {
+            let count = kana_count[word.kana.as_ref()];
+            if count > 1 && !reported.contains(&word.kana) {
+                reported.push(word.kana.clone());
+                issue(
+                    IssueKind::DuplicateKana,
+                    Severity::Error,
+                    format!(
+                        "`{}` is defined {} times; only the last definition is used",
+                        word.kana, count
+                    ),
+                );
+            }
+
+            for (i, sense) in word.senses.iter().enumerate() {
+                if word.senses[..i].iter().any(|s| s.wtype == sense.wtype) {
+                    issue(
+                        IssueKind::DuplicateKana,
+                        Severity::Error,
+                        format!(
+                            "`{}` has more than one {} sense; only the first is used when \
+                             translating from Kana",
+                            word.kana, sense.wtype
+                        ),
+                    );
+                }
+            }
+        }
+
+        let mut conflicts: Vec<(&String, &Vec<&str>)> = gloss_owners
+            .iter()
+            .filter(|(_, owners)| owners.len() > 1)
+            .collect();
+        conflicts.sort();
+        for (gloss, owners) in conflicts {
+            issue(
+                IssueKind::ConflictingGloss,
+                Severity::Warning,
+                format!(
+                    "`{}` is a gloss of {}; it translates to `{}`",
+                    gloss,
+                    owners
+                        .iter()
+                        .map(|k| format!("`{}`", k))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    self.translate_gloss(gloss).unwrap_or_default()
+                ),
+            );
+        }
+
+        let mut kanas: Vec<&str> = self.words.iter().map(|w| w.kana.as_ref()).collect();
+        kanas.sort();
+        kanas.dedup();
+        for kana in kanas {
+            let Some(senses) = self.senses(kana) else {
+                continue;
+            };
+            for gloss in senses.iter().filter_map(Sense::gloss) {
+                match self.translate_gloss(gloss) {
+                    Some(back) if back == kana => {}
+                    Some(back) => issue(
+                        IssueKind::NoRoundTrip,
+                        Severity::Warning,
+                        format!(
+                            "`{}` translates to `{}`, which translates back to `{}`",
+                            kana, gloss, back
+                        ),
+                    ),
+                    None => {}
+                }
+            }
+        }
+
+        for word in self.words.iter().filter(|w| w.kana.contains(' ')) {
+            for part in word.kana.split(' ') {
+                if self.entry(part).is_none() {
+                    issue(
+                        IssueKind::CompoundPart,
+                        Severity::Error,
+                        format!(
+                            "compound `{}` uses `{}`, which is not in the dictionary",
+                            word.kana, part
+                        ),
+                    );
+                }
+            }
+        }
+
+        for word in self.words.iter() {
+            for example in word.senses.iter().flat_map(|s| s.examples.iter()) {
+                let diagnostics = crate::lint::lint_with(self, example);
+                if let Some(first) = diagnostics.iter().find(|d| d.severity == Severity::Error) {
+                    issue(
+                        IssueKind::BadExample,
+                        Severity::Warning,
+                        format!(
+                            "example `{}` for `{}`: {}",
+                            example, word.kana, first.message
+                        ),
+                    );
+                }
+                if !format!(" {} ", example).contains(&format!(" {} ", word.kana)) {
+                    issue(
+                        IssueKind::BadExample,
+                        Severity::Warning,
+                        format!("example `{}` does not use `{}`", example, word.kana),
+                    );
+                }
+            }
+        }
+
+        for particle in MARKERS {
+            match self.get_word_type(particle) {
+                _ if self.has_type(particle, WordType::Particle) => {}
+                Some(other) => issue(
+                    IssueKind::ParticleType,
+                    Severity::Error,
+                    format!("particle `{}` is typed as {}", particle, other),
+                ),
+                None => issue(
+                    IssueKind::ParticleType,
+                    Severity::Error,
+                    format!("particle `{}` is missing", particle),
+                ),
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(text: &str) -> Dictionary {
+        Dictionary::from_reader(text.as_bytes()).unwrap()
+    }
+
+    fn parse_error(text: &str) -> (usize, String) {
+        match Dictionary::from_reader(text.as_bytes()) {
+            Err(DictError::Parse { line, message }) => (line, message),
+            other => panic!("expected a parse error, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn reads_senses_glosses_and_examples() {
+        let dict = read(
+            "# kana\ttype\tglosses\n\
+             \n\
+             moku\taction\teat, consume\tmi moku | jan li moku\n\
+             moku\tentity\tfood\n",
+        );
+        let senses = dict.senses("moku").unwrap();
+        assert_eq!(senses.len(), 2);
+        assert_eq!(senses[0].wtype, WordType::Action);
+        assert_eq!(senses[0].glosses[..], ["eat", "consume"]);
+        assert_eq!(senses[0].examples[..], ["mi moku", "jan li moku"]);
+        assert_eq!(senses[1].wtype, WordType::Entity);
+        assert_eq!(dict.translate_gloss("Consume"), Some("moku"));
+    }
+
+    #[test]
+    fn multi_word_kana_and_glosses() {
+        let dict = read("tomo  Tawa\tentity\tcar\npona\tparticle\tthank you\n");
+        assert!(dict.entry("tomo tawa").is_some());
+        assert_eq!(dict.longest_compound(), 2);
+        assert_eq!(dict.longest_phrase(), 2);
+        let (n, found) = dict.lookup_phrase(&["thank", "you", "all"]).unwrap();
+        assert_eq!((n, found.kana), (2, "pona"));
+    }
+
+    #[test]
+    fn reports_the_line_of_a_bad_entry() {
+        assert_eq!(
+            parse_error("moku\taction\teat\nlape\taction\n"),
+            (
+                2,
+                "expected 3 or 4 tab-separated columns, found 2".to_string()
+            )
+        );
+        assert_eq!(
+            parse_error("# words\nmoku\tverb\teat\n"),
+            (2, "unknown word type `verb`".to_string())
+        );
+        assert_eq!(
+            parse_error("moku\taction\t , \n"),
+            (1, "`moku` has no gloss".to_string())
+        );
+    }
+
+    #[test]
+    fn builtin_dictionaries_load() {
+        for lang in Lang::ALL {
+            let dict = Dictionary::builtin(lang);
+            assert_eq!(dict.lang(), lang);
+            assert!(dict.entry("moku").is_some());
+        }
+        assert_eq!(Dictionary::new().translate_gloss("food"), Some("moku"));
+    }
+
+    const PARTICLES: &str = "li\tparticle\t[li]\ne\tparticle\t[e]\nla\tparticle\t[la]\n\
+                             pi\tparticle\t[pi]\nen\tparticle\t[en]\nse\tparticle\t[se]\n";
+
+    fn issues(words: &str) -> Vec<(IssueKind, Severity)> {
+        read(&format!("{}{}", PARTICLES, words))
+            .validate()
+            .iter()
+            .map(|issue| (issue.kind, issue.severity))
+            .collect()
+    }
+
+    #[test]
+    fn builtin_dictionaries_are_clean() {
+        for lang in Lang::ALL {
+            assert_eq!(Dictionary::builtin(lang).validate(), []);
+        }
+        assert_eq!(issues("moku\taction\teat\nmoku\tentity\tfood\n"), []);
+    }
+
+    #[test]
+    fn duplicate_senses_are_errors() {
+        assert_eq!(
+            issues("moku\taction\teat\nmoku\taction\tconsume\n"),
+            [(IssueKind::DuplicateKana, Severity::Error)]
+        );
+    }
+
+    #[test]
+    fn a_gloss_of_two_words_does_not_round_trip() {
+        assert_eq!(
+            issues("moku\taction\teat\npan\tentity\teat\n"),
+            [
+                (IssueKind::ConflictingGloss, Severity::Warning),
+                (IssueKind::NoRoundTrip, Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn particles_must_be_typed_and_present() {
+        let dict = read("li\taction\tgo\n");
+        let messages: Vec<String> = dict
+            .validate()
+            .into_iter()
+            .filter(|issue| issue.kind == IssueKind::ParticleType)
+            .map(|issue| issue.message)
+            .collect();
+        assert_eq!(messages[0], "particle `li` is typed as action");
+        assert_eq!(messages[1], "particle `e` is missing");
+    }
+
+    #[test]
+    fn compounds_and_examples_are_checked() {
+        assert_eq!(
+            issues("tomo tawa\tentity\tcar\ntomo\tentity\thouse\n"),
+            [(IssueKind::CompoundPart, Severity::Error)]
+        );
+        assert_eq!(
+            issues("moku\taction\teat\tjan li lape\njan\tentity\tperson\nlape\taction\tsleep\n"),
+            [(IssueKind::BadExample, Severity::Warning)]
+        );
+    }
+
+    #[test]
+    fn overlay_merges_senses_and_tracks_layers() {
+        let base = read("moku\taction\teat\nmoku\tentity\tfood\n");
+        let glossary =
+            read("moku\tentity\tsnack\nmoku\tquality\thungry\nilo sona\tentity\tcomputer\n");
+        let dict = base.overlay("team", &glossary);
+
+        let senses = dict.senses("moku").unwrap();
+        assert_eq!(senses.len(), 3);
+        assert_eq!(senses[1].glosses[..], ["snack", "food"]);
+        assert_eq!(dict.translate_gloss("food"), Some("moku"));
+        assert_eq!(dict.translate_gloss("computer"), Some("ilo sona"));
+
+        assert_eq!(dict.layers(), ["custom", "team"]);
+        assert_eq!(dict.layer_of("moku"), Some("custom"));
+        assert_eq!(dict.sense_layer("moku", 1), Some("team"));
+        assert_eq!(dict.sense_layer("moku", 2), Some("team"));
+        assert_eq!(dict.layer_of("ilo sona"), Some("team"));
+        assert_eq!(dict.longest_compound(), 2);
+    }
+
+    #[test]
+    fn overlay_keeps_the_base_language() {
+        let glossary = read("moku\tentity\tprzekąska\n");
+        let dict = Dictionary::builtin(Lang::Polish).overlay("team", &glossary);
+        assert_eq!(dict.lang(), Lang::Polish);
+        assert_eq!(dict.translate_gloss("przekąska"), Some("moku"));
+    }
+
+    #[test]
+    fn likely_lang_spots_a_glossary_in_another_language() {
+        let english = read("moku\tentity\tfood, snack\ntomo\tentity\thouse, office\n");
+        assert_eq!(english.likely_lang(), Some(Lang::English));
+        let polish = read("moku\tentity\tjedzenie\n");
+        assert_eq!(polish.likely_lang(), Some(Lang::Polish));
+        let jargon = read("ilo sona\tentity\tkubernetes\n");
+        assert_eq!(jargon.likely_lang(), None);
+    }
+
+    #[test]
+    fn lemmas_are_verbs_or_nouns_never_pronouns() {
+        let dict = Dictionary::new();
+        let found = dict.lookup("eating").unwrap();
+        assert_eq!(
+            (found.kana, found.lemma.tense),
+            ("moku", Tense::Progressive)
+        );
+        assert_eq!(dict.lookup("houses").unwrap().kana, "tomo");
+        assert_eq!(dict.lookup("us").unwrap().kana, "mi");
+        for word in ["seed", "shed", "wing", "wed"] {
+            assert!(dict.lookup(word).is_none(), "{}", word);
+        }
     }
 }
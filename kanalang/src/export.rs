@@ -0,0 +1,252 @@
+//! Serialises a dictionary for docs and flashcards.
+
+use crate::dict::{Dictionary, Sense, Word};
+use crate::lang::Lang;
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// An array of words, each with its senses.
+    Json,
+    /// One row per sense: kana, type, glosses, examples.
+    Csv,
+    /// A table like the README's, one row per sense.
+    Markdown,
+    /// One card per word, for Anki's "Import File" (tab-separated, HTML).
+    AnkiTsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            "anki-tsv" | "anki" => Ok(Format::AnkiTsv),
+            other => Err(format!(
+                "unknown export format `{}`; expected json, csv, markdown or anki-tsv",
+                other
+            )),
+        }
+    }
+}
+
+pub fn export(dict: &Dictionary, format: Format) -> String {
+    match format {
+        Format::Json => to_json(dict.words()),
+        Format::Csv => to_csv(dict.words()),
+        Format::Markdown => to_markdown(dict.words(), dict.lang()),
+        Format::AnkiTsv => to_anki(dict.words()),
+    }
+}
+
+fn senses(words: &[Word]) -> impl Iterator<Item = (&Word, &Sense)> {
+    words
+        .iter()
+        .flat_map(|word| word.senses.iter().map(move |sense| (word, sense)))
+}
+
+fn to_json(words: &[Word]) -> String {
+    let strings = |items: &[std::borrow::Cow<'static, str>]| {
+        let quoted: Vec<String> = items.iter().map(|item| json_string(item)).collect();
+        format!("[{}]", quoted.join(", "))
+    };
+
+    let mut out = String::from("[\n");
+    for (i, word) in words.iter().enumerate() {
+        let senses: Vec<String> = word
+            .senses
+            .iter()
+            .map(|sense| {
+                format!(
+                    "      {{ \"type\": {}, \"glosses\": {}, \"examples\": {} }}",
+                    json_string(sense.wtype.name()),
+                    strings(&sense.glosses),
+                    strings(&sense.examples)
+                )
+            })
+            .collect();
+        let comma = if i + 1 < words.len() { "," } else { "" };
+        writeln!(
+            out,
+            "  {{\n    \"kana\": {},\n    \"senses\": [\n{}\n    ]\n  }}{}",
+            json_string(&word.kana),
+            senses.join(",\n"),
+            comma
+        )
+        .unwrap();
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn to_csv(words: &[Word]) -> String {
+    let mut out = String::from("kana,type,glosses,examples\n");
+    for (word, sense) in senses(words) {
+        let fields = [
+            word.kana.to_string(),
+            sense.wtype.name().to_string(),
+            sense.glosses.join(", "),
+            sense.examples.join(" | "),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        writeln!(out, "{}", fields.join(",")).unwrap();
+    }
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// The glosses column is headed with the dictionary's language.
+fn to_markdown(words: &[Word], lang: Lang) -> String {
+    let mut out = format!("| Kana | Type | {} | Examples |\n", lang);
+    writeln!(
+        out,
+        "|------|------|{}|----------|",
+        "-".repeat(lang.name().len() + 2)
+    )
+    .unwrap();
+    for (word, sense) in senses(words) {
+        let examples: Vec<String> = sense
+            .examples
+            .iter()
+            .map(|example| format!("`{}`", example))
+            .collect();
+        writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            markdown_cell(&word.kana),
+            sense.wtype,
+            markdown_cell(&sense.glosses.join(", ")),
+            markdown_cell(&examples.join(", "))
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+/// Front: kana. Back: each sense's type and glosses, then its examples.
+fn to_anki(words: &[Word]) -> String {
+    let mut out = String::from("#separator:tab\n#html:true\n");
+    for word in words {
+        let back: Vec<String> = word
+            .senses
+            .iter()
+            .map(|sense| {
+                let mut line = format!(
+                    "<i>{}</i> {}",
+                    sense.wtype,
+                    html_escape(&sense.glosses.join(", "))
+                );
+                for example in sense.examples.iter() {
+                    write!(line, "<br>{}", html_escape(example)).unwrap();
+                }
+                line
+            })
+            .collect();
+        writeln!(out, "{}\t{}", html_escape(&word.kana), back.join("<br>")).unwrap();
+    }
+    out
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\t', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dict() -> Dictionary {
+        Dictionary::from_reader(
+            "moku\taction\teat, consume\tmi moku | jan li moku\n\
+             moku\tentity\tfood \"a|b\" <c>\n"
+                .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_nests_senses_in_words() {
+        assert_eq!(
+            export(&dict(), Format::Json),
+            "[\n  {\n    \"kana\": \"moku\",\n    \"senses\": [\n      \
+             { \"type\": \"action\", \"glosses\": [\"eat\", \"consume\"], \
+             \"examples\": [\"mi moku\", \"jan li moku\"] },\n      \
+             { \"type\": \"entity\", \"glosses\": [\"food \\\"a|b\\\" <c>\"], \
+             \"examples\": [] }\n    ]\n  }\n]\n"
+        );
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_commas_and_quotes() {
+        assert_eq!(
+            export(&dict(), Format::Csv),
+            "kana,type,glosses,examples\n\
+             moku,action,\"eat, consume\",mi moku | jan li moku\n\
+             moku,entity,\"food \"\"a|b\"\" <c>\",\n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_names_the_language() {
+        let markdown = export(&dict().with_lang(Lang::Polish), Format::Markdown);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "| Kana | Type | Polish | Examples |");
+        assert_eq!(lines[1], "|------|------|--------|----------|");
+        assert_eq!(
+            lines[2],
+            "| moku | action | eat, consume | `mi moku`, `jan li moku` |"
+        );
+        assert_eq!(lines[3], "| moku | entity | food \"a\\|b\" <c> |  |");
+    }
+
+    #[test]
+    fn anki_has_one_card_per_word() {
+        assert_eq!(
+            export(&dict(), Format::AnkiTsv),
+            "#separator:tab\n#html:true\n\
+             moku\t<i>action</i> eat, consume<br>mi moku<br>jan li moku\
+             <br><i>entity</i> food \"a|b\" &lt;c&gt;\n"
+        );
+    }
+
+    #[test]
+    fn format_names() {
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert_eq!("Anki".parse(), Ok(Format::AnkiTsv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
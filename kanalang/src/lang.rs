@@ -0,0 +1,1105 @@
+//! Natural languages Kana translates to and from.
+//!
+//! Each language has its own built-in dictionary (`data/dictionary.tsv` for
+//! English, `data/dictionary.<code>.tsv` for the others) and a small set of
+//! [`Rules`]: which words have no Kana equivalent, how pronouns map onto
+//! `mi`, `sina` and `ona`, how contractions split, and in what order
+//! translated words come out.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Lang {
+    #[default]
+    English,
+    Polish,
+    Spanish,
+    German,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 4] = [Lang::English, Lang::Polish, Lang::Spanish, Lang::German];
+
+    /// ISO 639-1 code, as used by `--lang` and dictionary file names.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::English => "en",
+            Lang::Polish => "pl",
+            Lang::Spanish => "es",
+            Lang::German => "de",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lang::English => "English",
+            Lang::Polish => "Polish",
+            Lang::Spanish => "Spanish",
+            Lang::German => "German",
+        }
+    }
+
+    pub fn rules(&self) -> &'static Rules {
+        match self {
+            Lang::English => &ENGLISH,
+            Lang::Polish => &POLISH,
+            Lang::Spanish => &SPANISH,
+            Lang::German => &GERMAN,
+        }
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        Lang::ALL
+            .into_iter()
+            .find(|lang| lang.code() == s || lang.name().to_lowercase() == s)
+            .ok_or_else(|| format!("unknown language `{}`; expected en, pl, es or de", s))
+    }
+}
+
+/// Tokenization and word-order rules for one language.
+#[derive(Debug)]
+pub struct Rules {
+    /// Contracted forms split into their parts before lookup ("al" → "a el").
+    pub contractions: &'static [(&'static str, &'static [&'static str])],
+    /// Articles, dropped: Kana has none.
+    pub articles: &'static [&'static str],
+    /// Words that negate, translated as `ala`.
+    pub negations: &'static [&'static str],
+    /// Words that intensify, translated as `mute`.
+    pub intensifiers: &'static [&'static str],
+    /// Words joining subjects, predicates or objects ("and").
+    pub conjunctions: &'static [&'static str],
+    /// Words offering a choice ("or"), translated as `anu`.
+    pub disjunctions: &'static [&'static str],
+    /// Tense helpers with no Kana equivalent ("will"), dropped.
+    pub auxiliaries: &'static [&'static str],
+    /// Helpers dropped when they open a question or come before a negation
+    /// ("do you see?", "I do not see").
+    pub question_auxiliaries: &'static [&'static str],
+    /// Forms of "to be", dropped before an adjective or noun predicate, each
+    /// with the Kana subject it implies when the subject is left out.
+    pub copulas: &'static [(&'static str, &'static str)],
+    /// Prepositions and the Kana preposition for each. The first listed for
+    /// a Kana preposition is the one it translates back to.
+    pub prepositions: &'static [(&'static str, &'static str)],
+    /// Personal pronouns in any case, and the Kana pronoun for each.
+    pub pronouns: &'static [(&'static str, &'static str)],
+    /// Possessive determiners, and the Kana pronoun that follows the noun
+    /// for each ("my house" → `tomo mi`). The first for each Kana is used
+    /// when translating back.
+    pub possessives: &'static [(&'static str, &'static str)],
+    /// Words joining a noun to its owner after it ("the house of ...").
+    pub genitives: &'static [&'static str],
+    /// Pronouns that are only ever objects and come before the verb, so a
+    /// sentence may open with one ("te quiero").
+    pub clitics: &'static [&'static str],
+    /// `mi`, `sina` and `ona`, alone or with `mute`, as subjects.
+    pub subject_pronouns: &'static [(&'static str, &'static str)],
+    /// `mi`, `sina` and `ona`, alone or with `mute`, as objects.
+    pub object_pronouns: &'static [(&'static str, &'static str)],
+    /// "To be" after `mi`, `sina` and `ona`, alone or with `mute`, and
+    /// after several subjects (as `ona`).
+    pub be_pronouns: &'static [(&'static str, &'static str)],
+    /// "To be" after any other subject.
+    pub be: &'static str,
+    /// Present-tense endings that replace an infinitive ending, for `mi`,
+    /// `sina`, any other subject, `mi mute`, `sina mute` and `ona` (which is
+    /// plural, as in `be_pronouns`). The first matching ending is used.
+    pub conjugations: &'static [(&'static str, [&'static str; 6])],
+    /// Verbs that `conjugations` gets wrong, with their six forms.
+    pub irregular_verbs: &'static [(&'static str, [&'static str; 6])],
+    /// Negation goes after "to be" ("I am not", "ich bin nicht") rather
+    /// than before it.
+    pub not_after_be: bool,
+    /// Joins subjects, predicates and objects.
+    pub and: &'static str,
+    /// Offers a choice between phrases.
+    pub or: &'static str,
+    /// Opens a `la` context that has a predicate: "if you eat, I sleep".
+    pub condition: &'static str,
+    /// Joins a noun to a `pi` group that owns it; empty where the order
+    /// alone says so.
+    pub of: &'static str,
+    /// Negates a verb.
+    pub not: &'static str,
+    /// Negates a noun ("no food").
+    pub no: &'static str,
+    /// Negation goes after the predicate ("ich sehe dich nicht") rather than
+    /// before the verb.
+    pub negation_last: bool,
+    /// Adjectives go before the noun ("big house"); otherwise after it, as
+    /// in Kana.
+    pub adjectives_first: bool,
+    /// Object pronouns go before the verb ("yo te amo").
+    pub object_pronouns_first: bool,
+    /// After a fronted time or condition the verb comes before its subject
+    /// ("heute esse ich").
+    pub verb_second: bool,
+    /// Verb endings that show the subject, for languages that may leave the
+    /// subject pronoun out ("quiero" → `mi`). Empty for the others.
+    pub person_endings: &'static [(&'static str, &'static str)],
+}
+
+impl Rules {
+    /// The Kana pronoun for a natural-language pronoun.
+    pub fn pronoun(&self, word: &str) -> Option<&'static str> {
+        find(self.pronouns, word)
+    }
+
+    pub fn subject_pronoun(&self, kana: &str) -> Option<&'static str> {
+        find(self.subject_pronouns, kana)
+    }
+
+    pub fn object_pronoun(&self, kana: &str) -> Option<&'static str> {
+        find(self.object_pronouns, kana)
+    }
+
+    /// The Kana subject implied by a verb with no subject before it: the one
+    /// for its longest matching ending, else `ona`. `None` if the language
+    /// always states its subjects.
+    pub fn implied_subject(&self, verb: &str) -> Option<&'static str> {
+        if self.person_endings.is_empty() {
+            return None;
+        }
+        let subject = self
+            .person_endings
+            .iter()
+            .filter(|(ending, _)| verb.ends_with(ending))
+            .max_by_key(|(ending, _)| ending.len())
+            .map_or("ona", |(_, kana)| kana);
+        Some(subject)
+    }
+
+    pub fn possessive(&self, word: &str) -> Option<&'static str> {
+        find(self.possessives, word)
+    }
+
+    /// The possessive determiner for a Kana pronoun ("mi" → "my").
+    pub fn possessive_for(&self, kana: &str) -> Option<&'static str> {
+        self.possessives
+            .iter()
+            .find(|(_, k)| *k == kana)
+            .map(|(word, _)| *word)
+    }
+
+    pub fn preposition(&self, word: &str) -> Option<&'static str> {
+        find(self.prepositions, word)
+    }
+
+    /// How many of `words` a preposition of several words ("za pomocą")
+    /// at their start takes up.
+    pub fn long_preposition(&self, words: &[&str]) -> Option<usize> {
+        self.prepositions
+            .iter()
+            .map(|(preposition, _)| preposition.split(' ').count())
+            .filter(|&n| n > 1 && n <= words.len())
+            .filter(|&n| {
+                self.preposition(&words[..n].join(" ").to_lowercase())
+                    .is_some()
+            })
+            .max()
+    }
+
+    /// The word a Kana preposition translates back to.
+    pub fn preposition_for(&self, kana: &str) -> Option<&'static str> {
+        self.prepositions
+            .iter()
+            .find(|(_, k)| *k == kana)
+            .map(|(word, _)| *word)
+    }
+
+    pub fn is_copula(&self, word: &str) -> bool {
+        find(self.copulas, word).is_some()
+    }
+
+    /// The Kana subject implied by a form of "to be" with no subject before
+    /// it. `None` if the language always states its subjects.
+    pub fn copula_subject(&self, word: &str) -> Option<&'static str> {
+        if self.person_endings.is_empty() {
+            return None;
+        }
+        find(self.copulas, word)
+    }
+
+    /// "To be" for a Kana pronoun subject, or for any other subject.
+    pub fn be(&self, subject: Option<&str>) -> &'static str {
+        subject
+            .and_then(|kana| find(self.be_pronouns, kana))
+            .unwrap_or(self.be)
+    }
+
+    /// The present tense of an infinitive for a Kana pronoun subject, or
+    /// for any other subject. Only the first word of a verb of several
+    /// words ("uczyć się") changes.
+    pub fn conjugate(&self, verb: &str, subject: Option<&str>) -> String {
+        let person = match subject {
+            Some("mi") => 0,
+            Some("sina") => 1,
+            Some("mi mute") => 3,
+            Some("sina mute") => 4,
+            Some("ona" | "ona mute") => 5,
+            _ => 2,
+        };
+        let (first, rest) = match verb.split_once(' ') {
+            Some((first, rest)) => (first, format!(" {}", rest)),
+            None => (verb, String::new()),
+        };
+        if let Some(forms) = find(self.irregular_verbs, first) {
+            return format!("{}{}", forms[person], rest);
+        }
+        for (ending, endings) in self.conjugations {
+            if let Some(stem) = first.strip_suffix(ending) {
+                return format!("{}{}{}", stem, endings[person], rest);
+            }
+        }
+        verb.to_string()
+    }
+
+    pub fn contraction(&self, word: &str) -> Option<&'static [&'static str]> {
+        find(self.contractions, word)
+    }
+
+    /// Whether `word` is a helper with no Kana equivalent. `next` is the
+    /// word after it.
+    pub fn is_auxiliary(&self, word: &str, next: Option<&str>, opens_question: bool) -> bool {
+        self.auxiliaries.contains(&word)
+            || (self.question_auxiliaries.contains(&word)
+                && (opens_question || next.is_some_and(|next| self.negations.contains(&next))))
+    }
+}
+
+fn find<T: Copy>(table: &[(&str, T)], key: &str) -> Option<T> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+static ENGLISH: Rules = Rules {
+    // Apostrophe contractions ("can't", "I'm") are split by the parser.
+    contractions: &[],
+    articles: &["the", "a", "an"],
+    negations: &["not", "no"],
+    intensifiers: &["very", "really"],
+    conjunctions: &["and"],
+    disjunctions: &["or"],
+    auxiliaries: &["will", "would", "shall"],
+    question_auxiliaries: &["do", "does", "did"],
+    copulas: &[
+        ("am", "mi"),
+        ("are", "sina"),
+        ("is", "ona"),
+        ("was", "ona"),
+        ("were", "sina"),
+        ("be", "ona"),
+        ("been", "ona"),
+        ("being", "ona"),
+    ],
+    prepositions: &[
+        ("in", "lon"),
+        ("at", "lon"),
+        ("on", "lon"),
+        ("to", "tawa"),
+        ("towards", "tawa"),
+        ("into", "tawa"),
+        ("from", "tan"),
+        ("with", "kepeken"),
+        ("like", "sama"),
+    ],
+    pronouns: &[
+        ("i", "mi"),
+        ("me", "mi"),
+        ("we", "mi"),
+        ("us", "mi"),
+        ("you", "sina"),
+        ("he", "ona"),
+        ("him", "ona"),
+        ("she", "ona"),
+        ("her", "ona"),
+        ("it", "ona"),
+        ("they", "ona"),
+        ("them", "ona"),
+    ],
+    possessives: &[
+        ("my", "mi"),
+        ("your", "sina"),
+        ("their", "ona"),
+        ("his", "ona"),
+        ("her", "ona"),
+        ("its", "ona"),
+        ("our", "mi mute"),
+    ],
+    genitives: &["of"],
+    clitics: &[],
+    subject_pronouns: &[
+        ("mi", "I"),
+        ("sina", "you"),
+        ("ona", "they"),
+        ("mi mute", "we"),
+        ("sina mute", "you"),
+        ("ona mute", "they"),
+    ],
+    object_pronouns: &[
+        ("mi", "me"),
+        ("sina", "you"),
+        ("ona", "them"),
+        ("mi mute", "us"),
+        ("sina mute", "you"),
+        ("ona mute", "them"),
+    ],
+    be_pronouns: &[
+        ("mi", "am"),
+        ("sina", "are"),
+        ("ona", "are"),
+        ("mi mute", "are"),
+        ("sina mute", "are"),
+        ("ona mute", "are"),
+    ],
+    be: "is",
+    // English verbs only change after "he", "she" and "it", which Kana
+    // doesn't tell apart from "they".
+    conjugations: &[],
+    irregular_verbs: &[],
+    not_after_be: true,
+    and: "and",
+    or: "or",
+    condition: "if",
+    of: "of",
+    not: "do not",
+    no: "no",
+    negation_last: false,
+    adjectives_first: true,
+    object_pronouns_first: false,
+    verb_second: false,
+    person_endings: &[],
+};
+
+static POLISH: Rules = Rules {
+    contractions: &[],
+    articles: &[],
+    negations: &["nie"],
+    intensifiers: &["bardzo"],
+    conjunctions: &["i", "oraz"],
+    disjunctions: &["albo", "lub", "czy"],
+    auxiliaries: &[
+        "będę",
+        "będziesz",
+        "będzie",
+        "będziemy",
+        "będziecie",
+        "będą",
+    ],
+    // "czy" turns a statement into a yes/no question.
+    question_auxiliaries: &["czy"],
+    copulas: &[
+        ("jestem", "mi"),
+        ("jesteś", "sina"),
+        ("jest", "ona"),
+        ("jesteśmy", "mi"),
+        ("jesteście", "sina"),
+        ("są", "ona"),
+        ("byłem", "mi"),
+        ("byłam", "mi"),
+        ("byłeś", "sina"),
+        ("byłaś", "sina"),
+        ("był", "ona"),
+        ("była", "ona"),
+        ("było", "ona"),
+        ("byli", "ona"),
+        ("były", "ona"),
+        ("być", "ona"),
+    ],
+    prepositions: &[
+        ("w", "lon"),
+        ("we", "lon"),
+        ("na", "lon"),
+        ("do", "tawa"),
+        ("ku", "tawa"),
+        ("z", "tan"),
+        ("ze", "tan"),
+        ("od", "tan"),
+        ("za pomocą", "kepeken"),
+        ("jak", "sama"),
+    ],
+    pronouns: &[
+        ("ja", "mi"),
+        ("mnie", "mi"),
+        ("mi", "mi"),
+        ("my", "mi"),
+        ("nas", "mi"),
+        ("nam", "mi"),
+        ("ty", "sina"),
+        ("ciebie", "sina"),
+        ("cię", "sina"),
+        ("tobie", "sina"),
+        ("ci", "sina"),
+        ("wy", "sina"),
+        ("was", "sina"),
+        ("on", "ona"),
+        ("ona", "ona"),
+        ("ono", "ona"),
+        ("oni", "ona"),
+        ("one", "ona"),
+        ("go", "ona"),
+        ("jego", "ona"),
+        ("ją", "ona"),
+        ("jej", "ona"),
+        ("ich", "ona"),
+        ("im", "ona"),
+    ],
+    possessives: &[
+        ("mój", "mi"),
+        ("moja", "mi"),
+        ("moje", "mi"),
+        ("mojego", "mi"),
+        ("mojej", "mi"),
+        ("moją", "mi"),
+        ("moi", "mi"),
+        ("moich", "mi"),
+        ("twój", "sina"),
+        ("twoja", "sina"),
+        ("twoje", "sina"),
+        ("twojego", "sina"),
+        ("twojej", "sina"),
+        ("twoją", "sina"),
+        ("ich", "ona"),
+        ("jego", "ona"),
+        ("jej", "ona"),
+        ("nasz", "mi mute"),
+        ("nasza", "mi mute"),
+        ("nasze", "mi mute"),
+        ("naszego", "mi mute"),
+        ("naszej", "mi mute"),
+        ("naszą", "mi mute"),
+        ("wasz", "sina mute"),
+        ("wasza", "sina mute"),
+        ("wasze", "sina mute"),
+    ],
+    // The owner is in the genitive case, with no word before it.
+    genitives: &[],
+    clitics: &[],
+    subject_pronouns: &[
+        ("mi", "ja"),
+        ("sina", "ty"),
+        ("ona", "oni"),
+        ("mi mute", "my"),
+        ("sina mute", "wy"),
+        ("ona mute", "oni"),
+    ],
+    object_pronouns: &[
+        ("mi", "mnie"),
+        ("sina", "cię"),
+        ("ona", "ich"),
+        ("mi mute", "nas"),
+        ("sina mute", "was"),
+        ("ona mute", "ich"),
+    ],
+    be_pronouns: &[
+        ("mi", "jestem"),
+        ("sina", "jesteś"),
+        ("ona", "są"),
+        ("mi mute", "jesteśmy"),
+        ("sina mute", "jesteście"),
+        ("ona mute", "są"),
+    ],
+    be: "jest",
+    // kochać → kocham; gotować → gotuję; uczyć → uczę; robić → robię
+    conjugations: &[
+        ("ować", ["uję", "ujesz", "uje", "ujemy", "ujecie", "ują"]),
+        ("ać", ["am", "asz", "a", "amy", "acie", "ają"]),
+        ("dzić", ["dzę", "dzisz", "dzi", "dzimy", "dzicie", "dzą"]),
+        ("yć", ["ę", "ysz", "y", "ymy", "ycie", "ą"]),
+        ("ić", ["ię", "isz", "i", "imy", "icie", "ią"]),
+    ],
+    irregular_verbs: &[
+        (
+            "brać",
+            [
+                "biorę",
+                "bierzesz",
+                "bierze",
+                "bierzemy",
+                "bierzecie",
+                "biorą",
+            ],
+        ),
+        (
+            "chcieć",
+            ["chcę", "chcesz", "chce", "chcemy", "chcecie", "chcą"],
+        ),
+        (
+            "ciąć",
+            ["tnę", "tniesz", "tnie", "tniemy", "tniecie", "tną"],
+        ),
+        (
+            "czuć",
+            ["czuję", "czujesz", "czuje", "czujemy", "czujecie", "czują"],
+        ),
+        (
+            "dawać",
+            ["daję", "dajesz", "daje", "dajemy", "dajecie", "dają"],
+        ),
+        (
+            "iść",
+            ["idę", "idziesz", "idzie", "idziemy", "idziecie", "idą"],
+        ),
+        (
+            "istnieć",
+            [
+                "istnieję",
+                "istniejesz",
+                "istnieje",
+                "istniejemy",
+                "istniejecie",
+                "istnieją",
+            ],
+        ),
+        (
+            "jechać",
+            [
+                "jadę",
+                "jedziesz",
+                "jedzie",
+                "jedziemy",
+                "jedziecie",
+                "jadą",
+            ],
+        ),
+        ("jeść", ["jem", "jesz", "je", "jemy", "jecie", "jedzą"]),
+        ("mieć", ["mam", "masz", "ma", "mamy", "macie", "mają"]),
+        (
+            "móc",
+            ["mogę", "możesz", "może", "możemy", "możecie", "mogą"],
+        ),
+        (
+            "psuć",
+            ["psuję", "psujesz", "psuje", "psujemy", "psujecie", "psują"],
+        ),
+        (
+            "rosnąć",
+            [
+                "rosnę",
+                "rośniesz",
+                "rośnie",
+                "rośniemy",
+                "rośniecie",
+                "rosną",
+            ],
+        ),
+        (
+            "słyszeć",
+            [
+                "słyszę",
+                "słyszysz",
+                "słyszy",
+                "słyszymy",
+                "słyszycie",
+                "słyszą",
+            ],
+        ),
+        ("spać", ["śpię", "śpisz", "śpi", "śpimy", "śpicie", "śpią"]),
+        (
+            "widzieć",
+            ["widzę", "widzisz", "widzi", "widzimy", "widzicie", "widzą"],
+        ),
+        (
+            "wiedzieć",
+            ["wiem", "wiesz", "wie", "wiemy", "wiecie", "wiedzą"],
+        ),
+        (
+            "zostać",
+            [
+                "zostaję",
+                "zostajesz",
+                "zostaje",
+                "zostajemy",
+                "zostajecie",
+                "zostają",
+            ],
+        ),
+    ],
+    not_after_be: false,
+    and: "i",
+    or: "albo",
+    condition: "jeśli",
+    of: "",
+    not: "nie",
+    no: "żaden",
+    negation_last: false,
+    adjectives_first: true,
+    object_pronouns_first: false,
+    verb_second: false,
+    // chcę, jem; chcesz, jesz; chcemy, jemy; chcecie, jecie
+    person_endings: &[
+        ("ę", "mi"),
+        ("m", "mi"),
+        ("sz", "sina"),
+        ("my", "mi"),
+        ("cie", "sina"),
+    ],
+};
+
+static SPANISH: Rules = Rules {
+    contractions: &[("al", &["a", "el"]), ("del", &["de", "el"])],
+    articles: &["el", "la", "los", "las", "un", "una", "unos", "unas", "lo"],
+    negations: &["no"],
+    intensifiers: &["muy"],
+    conjunctions: &["y", "e"],
+    disjunctions: &["o", "u"],
+    auxiliaries: &[],
+    question_auxiliaries: &[],
+    copulas: &[
+        ("soy", "mi"),
+        ("eres", "sina"),
+        ("es", "ona"),
+        ("somos", "mi"),
+        ("sois", "sina"),
+        ("son", "ona"),
+        ("estoy", "mi"),
+        ("estás", "sina"),
+        ("está", "ona"),
+        ("estamos", "mi"),
+        ("estáis", "sina"),
+        ("están", "ona"),
+        ("fui", "mi"),
+        ("fuiste", "sina"),
+        ("fue", "ona"),
+        ("era", "ona"),
+        ("ser", "ona"),
+        ("estar", "ona"),
+    ],
+    prepositions: &[
+        ("en", "lon"),
+        ("a", "tawa"),
+        ("hacia", "tawa"),
+        ("de", "tan"),
+        ("desde", "tan"),
+        ("con", "kepeken"),
+        ("como", "sama"),
+    ],
+    pronouns: &[
+        ("yo", "mi"),
+        ("me", "mi"),
+        ("mí", "mi"),
+        ("nosotros", "mi"),
+        ("nosotras", "mi"),
+        ("nos", "mi"),
+        ("tú", "sina"),
+        ("te", "sina"),
+        ("ti", "sina"),
+        ("usted", "sina"),
+        ("vosotros", "sina"),
+        ("ustedes", "sina"),
+        ("él", "ona"),
+        ("ella", "ona"),
+        ("ellos", "ona"),
+        ("ellas", "ona"),
+        ("le", "ona"),
+        ("les", "ona"),
+    ],
+    possessives: &[
+        ("mi", "mi"),
+        ("mis", "mi"),
+        ("tu", "sina"),
+        ("tus", "sina"),
+        ("su", "ona"),
+        ("sus", "ona"),
+        ("nuestro", "mi mute"),
+        ("nuestra", "mi mute"),
+        ("nuestros", "mi mute"),
+        ("nuestras", "mi mute"),
+        ("vuestro", "sina mute"),
+        ("vuestra", "sina mute"),
+        ("vuestros", "sina mute"),
+        ("vuestras", "sina mute"),
+    ],
+    genitives: &["de"],
+    clitics: &["me", "te", "nos", "os", "le", "les"],
+    subject_pronouns: &[
+        ("mi", "yo"),
+        ("sina", "tú"),
+        ("ona", "ellos"),
+        ("mi mute", "nosotros"),
+        ("sina mute", "vosotros"),
+        ("ona mute", "ellos"),
+    ],
+    object_pronouns: &[
+        ("mi", "me"),
+        ("sina", "te"),
+        ("ona", "los"),
+        ("mi mute", "nos"),
+        ("sina mute", "os"),
+        ("ona mute", "los"),
+    ],
+    be_pronouns: &[
+        ("mi", "soy"),
+        ("sina", "eres"),
+        ("ona", "son"),
+        ("mi mute", "somos"),
+        ("sina mute", "sois"),
+        ("ona mute", "son"),
+    ],
+    be: "es",
+    conjugations: &[
+        ("ar", ["o", "as", "a", "amos", "áis", "an"]),
+        ("er", ["o", "es", "e", "emos", "éis", "en"]),
+        ("ir", ["o", "es", "e", "imos", "ís", "en"]),
+    ],
+    irregular_verbs: &[
+        (
+            "crecer",
+            ["crezco", "creces", "crece", "crecemos", "crecéis", "crecen"],
+        ),
+        ("dar", ["doy", "das", "da", "damos", "dais", "dan"]),
+        (
+            "dirigir",
+            [
+                "dirijo",
+                "diriges",
+                "dirige",
+                "dirigimos",
+                "dirigís",
+                "dirigen",
+            ],
+        ),
+        (
+            "dormir",
+            [
+                "duermo", "duermes", "duerme", "dormimos", "dormís", "duermen",
+            ],
+        ),
+        (
+            "empezar",
+            [
+                "empiezo",
+                "empiezas",
+                "empieza",
+                "empezamos",
+                "empezáis",
+                "empiezan",
+            ],
+        ),
+        (
+            "hacer",
+            ["hago", "haces", "hace", "hacemos", "hacéis", "hacen"],
+        ),
+        ("ir", ["voy", "vas", "va", "vamos", "vais", "van"]),
+        (
+            "jugar",
+            ["juego", "juegas", "juega", "jugamos", "jugáis", "juegan"],
+        ),
+        ("oír", ["oigo", "oyes", "oye", "oímos", "oís", "oyen"]),
+        (
+            "poder",
+            ["puedo", "puedes", "puede", "podemos", "podéis", "pueden"],
+        ),
+        (
+            "quedarse",
+            [
+                "me quedo",
+                "te quedas",
+                "se queda",
+                "nos quedamos",
+                "os quedáis",
+                "se quedan",
+            ],
+        ),
+        (
+            "querer",
+            [
+                "quiero", "quieres", "quiere", "queremos", "queréis", "quieren",
+            ],
+        ),
+        (
+            "saber",
+            ["sé", "sabes", "sabe", "sabemos", "sabéis", "saben"],
+        ),
+        (
+            "sentir",
+            [
+                "siento", "sientes", "siente", "sentimos", "sentís", "sienten",
+            ],
+        ),
+        (
+            "tener",
+            ["tengo", "tienes", "tiene", "tenemos", "tenéis", "tienen"],
+        ),
+        (
+            "venir",
+            ["vengo", "vienes", "viene", "venimos", "venís", "vienen"],
+        ),
+        ("ver", ["veo", "ves", "ve", "vemos", "veis", "ven"]),
+    ],
+    not_after_be: false,
+    and: "y",
+    or: "o",
+    condition: "si",
+    of: "de",
+    not: "no",
+    no: "ningún",
+    negation_last: false,
+    adjectives_first: false,
+    object_pronouns_first: true,
+    verb_second: false,
+    // quiero, voy, sé; quieres, vas; queremos, vamos; queréis
+    person_endings: &[
+        ("o", "mi"),
+        ("oy", "mi"),
+        ("é", "mi"),
+        ("s", "sina"),
+        ("mos", "mi"),
+        ("is", "sina"),
+    ],
+};
+
+static GERMAN: Rules = Rules {
+    contractions: &[
+        ("am", &["an", "dem"]),
+        ("im", &["in", "dem"]),
+        ("ins", &["in", "das"]),
+        ("zum", &["zu", "dem"]),
+        ("zur", &["zu", "der"]),
+        ("vom", &["von", "dem"]),
+        ("beim", &["bei", "dem"]),
+    ],
+    articles: &[
+        "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "eines",
+    ],
+    negations: &["nicht", "kein", "keine", "keinen", "keinem", "keiner"],
+    intensifiers: &["sehr", "wirklich"],
+    conjunctions: &["und"],
+    disjunctions: &["oder"],
+    auxiliaries: &["werde", "wirst", "wird", "werden", "werdet"],
+    question_auxiliaries: &[],
+    copulas: &[
+        ("bin", "mi"),
+        ("bist", "sina"),
+        ("ist", "ona"),
+        ("sind", "ona"),
+        ("seid", "sina"),
+        ("war", "ona"),
+        ("waren", "ona"),
+        ("sein", "ona"),
+    ],
+    prepositions: &[
+        ("in", "lon"),
+        ("an", "lon"),
+        ("auf", "lon"),
+        ("zu", "tawa"),
+        ("nach", "tawa"),
+        ("von", "tan"),
+        ("aus", "tan"),
+        ("mit", "kepeken"),
+        ("wie", "sama"),
+    ],
+    pronouns: &[
+        ("ich", "mi"),
+        ("mich", "mi"),
+        ("mir", "mi"),
+        ("wir", "mi"),
+        ("uns", "mi"),
+        ("du", "sina"),
+        ("dich", "sina"),
+        ("dir", "sina"),
+        ("ihr", "sina"),
+        ("euch", "sina"),
+        ("er", "ona"),
+        ("sie", "ona"),
+        ("es", "ona"),
+        ("ihn", "ona"),
+        ("ihm", "ona"),
+        ("ihnen", "ona"),
+    ],
+    possessives: &[
+        ("mein", "mi"),
+        ("meine", "mi"),
+        ("meinen", "mi"),
+        ("meinem", "mi"),
+        ("meiner", "mi"),
+        ("meines", "mi"),
+        ("dein", "sina"),
+        ("deine", "sina"),
+        ("deinen", "sina"),
+        ("deinem", "sina"),
+        ("deiner", "sina"),
+        ("deines", "sina"),
+        ("ihr", "ona"),
+        ("ihre", "ona"),
+        ("ihren", "ona"),
+        ("ihrem", "ona"),
+        ("ihrer", "ona"),
+        ("ihres", "ona"),
+        ("sein", "ona"),
+        ("seine", "ona"),
+        ("seinen", "ona"),
+        ("seinem", "ona"),
+        ("seiner", "ona"),
+        ("seines", "ona"),
+        ("unser", "mi mute"),
+        ("unsere", "mi mute"),
+        ("unseren", "mi mute"),
+        ("unserem", "mi mute"),
+        ("unserer", "mi mute"),
+        ("euer", "sina mute"),
+        ("eure", "sina mute"),
+        ("euren", "sina mute"),
+        ("eurem", "sina mute"),
+    ],
+    genitives: &["von"],
+    clitics: &[],
+    subject_pronouns: &[
+        ("mi", "ich"),
+        ("sina", "du"),
+        ("ona", "sie"),
+        ("mi mute", "wir"),
+        ("sina mute", "ihr"),
+        ("ona mute", "sie"),
+    ],
+    object_pronouns: &[
+        ("mi", "mich"),
+        ("sina", "dich"),
+        ("ona", "sie"),
+        ("mi mute", "uns"),
+        ("sina mute", "euch"),
+        ("ona mute", "sie"),
+    ],
+    be_pronouns: &[
+        ("mi", "bin"),
+        ("sina", "bist"),
+        ("ona", "sind"),
+        ("mi mute", "sind"),
+        ("sina mute", "seid"),
+        ("ona mute", "sind"),
+    ],
+    be: "ist",
+    // schneiden → schneidest; benutzen → benutzt; lieben → liebst
+    conjugations: &[
+        ("den", ["de", "dest", "det", "den", "det", "den"]),
+        ("ten", ["te", "test", "tet", "ten", "tet", "ten"]),
+        ("zen", ["ze", "zt", "zt", "zen", "zt", "zen"]),
+        ("en", ["e", "st", "t", "en", "t", "en"]),
+    ],
+    irregular_verbs: &[
+        (
+            "anfangen",
+            [
+                "fange an",
+                "fängst an",
+                "fängt an",
+                "fangen an",
+                "fangt an",
+                "fangen an",
+            ],
+        ),
+        ("essen", ["esse", "isst", "isst", "essen", "esst", "essen"]),
+        ("geben", ["gebe", "gibst", "gibt", "geben", "gebt", "geben"]),
+        ("haben", ["habe", "hast", "hat", "haben", "habt", "haben"]),
+        (
+            "können",
+            ["kann", "kannst", "kann", "können", "könnt", "können"],
+        ),
+        (
+            "laufen",
+            ["laufe", "läufst", "läuft", "laufen", "lauft", "laufen"],
+        ),
+        ("mögen", ["mag", "magst", "mag", "mögen", "mögt", "mögen"]),
+        (
+            "nehmen",
+            ["nehme", "nimmst", "nimmt", "nehmen", "nehmt", "nehmen"],
+        ),
+        (
+            "schlafen",
+            [
+                "schlafe",
+                "schläfst",
+                "schläft",
+                "schlafen",
+                "schlaft",
+                "schlafen",
+            ],
+        ),
+        (
+            "sehen",
+            ["sehe", "siehst", "sieht", "sehen", "seht", "sehen"],
+        ),
+        (
+            "sprechen",
+            [
+                "spreche", "sprichst", "spricht", "sprechen", "sprecht", "sprechen",
+            ],
+        ),
+        (
+            "wachsen",
+            ["wachse", "wächst", "wächst", "wachsen", "wachst", "wachsen"],
+        ),
+        (
+            "wissen",
+            ["weiß", "weißt", "weiß", "wissen", "wisst", "wissen"],
+        ),
+        (
+            "wollen",
+            ["will", "willst", "will", "wollen", "wollt", "wollen"],
+        ),
+        (
+            "zerbrechen",
+            [
+                "zerbreche",
+                "zerbrichst",
+                "zerbricht",
+                "zerbrechen",
+                "zerbrecht",
+                "zerbrechen",
+            ],
+        ),
+    ],
+    not_after_be: true,
+    and: "und",
+    or: "oder",
+    condition: "wenn",
+    of: "von",
+    not: "nicht",
+    no: "kein",
+    negation_last: true,
+    adjectives_first: true,
+    object_pronouns_first: false,
+    verb_second: true,
+    person_endings: &[],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbs_agree_with_their_subject() {
+        let polish = Lang::Polish.rules();
+        assert_eq!(polish.conjugate("kochać", Some("mi")), "kocham");
+        assert_eq!(polish.conjugate("gotować", Some("ona")), "gotują");
+        assert_eq!(polish.conjugate("chcieć", Some("sina")), "chcesz");
+        assert_eq!(polish.conjugate("uczyć się", Some("mi mute")), "uczymy się");
+        let spanish = Lang::Spanish.rules();
+        assert_eq!(spanish.conjugate("comer", None), "come");
+        assert_eq!(spanish.conjugate("querer", Some("sina mute")), "queréis");
+        let german = Lang::German.rules();
+        assert_eq!(german.conjugate("schneiden", Some("sina")), "schneidest");
+        assert_eq!(german.conjugate("anfangen", None), "fängt an");
+        assert_eq!(Lang::English.rules().conjugate("eat", None), "eat");
+    }
+
+    #[test]
+    fn prepositions_of_several_words() {
+        let polish = Lang::Polish.rules();
+        assert_eq!(polish.long_preposition(&["Za", "pomocą", "noża"]), Some(2));
+        assert_eq!(polish.long_preposition(&["za"]), None);
+        assert_eq!(polish.long_preposition(&["w", "domu"]), None);
+    }
+}
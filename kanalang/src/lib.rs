@@ -1,5 +1,13 @@
+pub mod ast;
 pub mod dict;
+pub mod export;
+pub mod lang;
+pub mod lint;
+pub mod morph;
 pub mod parser;
+pub mod suggest;
 pub mod translator;
 
-pub use translator::Translator;
+pub use lang::Lang;
+pub use lint::{Diagnostic, Severity, lint, lint_with};
+pub use translator::{Note, Pivot, Translation, Translator};
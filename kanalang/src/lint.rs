@@ -0,0 +1,321 @@
+use crate::dict::{Dictionary, WordType};
+use crate::parser::{
+    E_WITHOUT_OBJECT, MARKERS, PI_WITHOUT_GROUP, Parser, SE_NOT_FIRST, Span, Token,
+};
+use crate::suggest::{did_you_mean, suggest};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+    /// A likely fix, such as "did you mean `moku`?".
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>, span: Span) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            span,
+            help: None,
+        }
+    }
+
+    pub fn warning(message: impl Into<String>, span: Span) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            message: message.into(),
+            span,
+            help: None,
+        }
+    }
+
+    pub fn with_help(mut self, help: Option<String>) -> Self {
+        self.help = help;
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+/// A lowercased word with its location.
+type SpannedWord = (String, Span);
+
+/// Checks Kana text against the grammar the chat system prompt teaches.
+pub fn lint(input: &str) -> Vec<Diagnostic> {
+    lint_with(&Dictionary::new(), input)
+}
+
+/// Like [`lint`], but checks vocabulary against `dict`.
+pub fn lint_with(dict: &Dictionary, input: &str) -> Vec<Diagnostic> {
+    let parser = Parser::new();
+    let tokens = parser.tokenize(input);
+    let mut diagnostics = Vec::new();
+
+    for segment in parser.sentences(&tokens) {
+        lint_sentence(dict, &parser, segment.tokens, &mut diagnostics);
+    }
+
+    diagnostics.sort_by_key(|d| d.span.start);
+    diagnostics
+}
+
+fn lint_sentence(
+    dict: &Dictionary,
+    parser: &Parser,
+    tokens: &[Token],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let words: Vec<SpannedWord> = tokens
+        .iter()
+        .filter_map(|token| Some((token.word()?.to_lowercase(), token.span)))
+        .collect();
+    if words.is_empty() {
+        return;
+    }
+
+    let before = diagnostics.len();
+    check_unknown_words(dict, &words, diagnostics);
+    check_se(&words, diagnostics);
+    check_dangling_e(&words, diagnostics);
+    check_pi(&words, diagnostics);
+
+    let mut start = opening(&words);
+    if words.get(start).is_some_and(|(w, _)| w == "se") {
+        start += 1;
+    }
+    for clause in words[start..].split(|(w, _)| w == "la") {
+        check_li(dict, clause, diagnostics);
+    }
+
+    // Anything the targeted checks missed still has to parse.
+    let has_error = diagnostics[before..]
+        .iter()
+        .any(|d| d.severity == Severity::Error);
+    if !has_error && let Err(err) = parser.parse_tokens(tokens) {
+        diagnostics.push(Diagnostic::error(err.message, err.span));
+    }
+}
+
+fn check_unknown_words(
+    dict: &Dictionary,
+    words: &[SpannedWord],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (word, span) in words {
+        // `[word]` is how untranslatable words are written.
+        if word.starts_with('[') && word.ends_with(']') {
+            continue;
+        }
+        if dict.translate_kana_word(word).is_none() {
+            let help = did_you_mean(&suggest(dict, word));
+            diagnostics
+                .push(Diagnostic::error(format!("unknown word `{}`", word), *span).with_help(help));
+        }
+    }
+}
+
+/// How many words open the sentence before `se` may: `anu`, then `yu`.
+fn opening(words: &[SpannedWord]) -> usize {
+    let mut start = 0;
+    for opener in ["anu", "yu"] {
+        if words.get(start).is_some_and(|(w, _)| w == opener) {
+            start += 1;
+        }
+    }
+    start
+}
+
+fn check_se(words: &[SpannedWord], diagnostics: &mut Vec<Diagnostic>) {
+    let start = opening(words);
+    for (i, (word, span)) in words.iter().enumerate() {
+        if word == "se" && i != start {
+            diagnostics.push(Diagnostic::error(SE_NOT_FIRST, *span));
+        }
+    }
+}
+
+fn check_dangling_e(words: &[SpannedWord], diagnostics: &mut Vec<Diagnostic>) {
+    for (i, (word, span)) in words.iter().enumerate() {
+        if word != "e" {
+            continue;
+        }
+        let next = words.get(i + 1).map(|(w, _)| w.as_str());
+        if next.is_none_or(|w| MARKERS.contains(&w)) {
+            diagnostics.push(Diagnostic::error(E_WITHOUT_OBJECT, *span));
+        }
+    }
+}
+
+fn check_pi(words: &[SpannedWord], diagnostics: &mut Vec<Diagnostic>) {
+    for (i, (word, span)) in words.iter().enumerate() {
+        if word != "pi" {
+            continue;
+        }
+        let group = words[i + 1..]
+            .iter()
+            .take_while(|(w, _)| !MARKERS.contains(&w.as_str()))
+            .filter(|(w, _)| w != "ala")
+            .count();
+        match group {
+            0 => diagnostics.push(Diagnostic::error(PI_WITHOUT_GROUP, *span)),
+            1 => diagnostics.push(Diagnostic::warning(
+                format!(
+                    "`pi` needs at least two modifiers; use `{}` without `pi`",
+                    words[i + 1].0
+                ),
+                *span,
+            )),
+            _ => {}
+        }
+    }
+}
+
+fn check_li(dict: &Dictionary, clause: &[SpannedWord], diagnostics: &mut Vec<Diagnostic>) {
+    let Some((subject, subject_span)) = clause.first() else {
+        return;
+    };
+    let has_li = clause.iter().any(|(w, _)| w == "li");
+
+    if subject == "mi" || subject == "sina" {
+        if let Some((next, span)) = clause.get(1)
+            && next == "li"
+        {
+            diagnostics.push(Diagnostic::warning(
+                format!("`li` is not used after `{}`", subject),
+                *span,
+            ));
+        }
+        return;
+    }
+
+    if has_li || MARKERS.contains(&subject.as_str()) {
+        return;
+    }
+
+    let has_object = clause.iter().any(|(w, _)| w == "e");
+    let has_action = clause[1..]
+        .iter()
+        .take_while(|(w, _)| w != "e")
+        .any(|(w, _)| dict.get_word_type(w) == Some(WordType::Action));
+    if has_object || has_action {
+        diagnostics.push(Diagnostic::error(
+            format!("missing `li` after subject `{}`", subject),
+            *subject_span,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(input: &str) -> Vec<(Severity, String)> {
+        lint(input)
+            .into_iter()
+            .map(|d| (d.severity, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn correct_sentences_have_no_diagnostics() {
+        assert!(lint("mi moku e telo. jan li lape.").is_empty());
+        assert!(lint("sina moku la mi lape").is_empty());
+        assert!(lint("tomo pi jan pona li suli").is_empty());
+        assert!(lint("mi moku; anu se sina lape?").is_empty());
+    }
+
+    #[test]
+    fn unknown_word_suggests_a_fix() {
+        let diagnostics = lint("mi mokuu");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "unknown word `mokuu`");
+        assert_eq!(diagnostics[0].span.range(), 3..8);
+        assert_eq!(diagnostics[0].help.as_deref(), Some("did you mean `moku`?"));
+    }
+
+    #[test]
+    fn missing_li_after_subject() {
+        let diagnostics = lint("jan moku e telo");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].message, "missing `li` after subject `jan`");
+        assert_eq!(diagnostics[0].span.range(), 0..3);
+    }
+
+    #[test]
+    fn li_after_mi_is_a_warning() {
+        let diagnostics = lint("mi li moku");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].span.range(), 3..5);
+    }
+
+    #[test]
+    fn se_must_start_the_sentence() {
+        assert_eq!(
+            messages("jan se li moku"),
+            [(Severity::Error, "`se` must start the sentence".to_string())]
+        );
+        assert!(lint("yu se sina pona").is_empty());
+    }
+
+    #[test]
+    fn pi_without_a_group_reads_like_the_parser() {
+        assert_eq!(
+            messages("tomo pi li suli"),
+            [(Severity::Error, PI_WITHOUT_GROUP.to_string())]
+        );
+    }
+
+    #[test]
+    fn e_needs_an_object() {
+        assert_eq!(
+            messages("jan li moku e li lape"),
+            [(
+                Severity::Error,
+                "`e` must be followed by an object".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn pi_with_one_modifier_is_a_warning() {
+        assert_eq!(
+            messages("tomo pi jan li suli"),
+            [(
+                Severity::Warning,
+                "`pi` needs at least two modifiers; use `jan` without `pi`".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn each_sentence_is_checked_on_its_own() {
+        let diagnostics = lint("mi moku. jan moku e telo");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.range(), 9..12);
+    }
+}
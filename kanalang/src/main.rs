@@ -1,7 +1,11 @@
 use colored::Colorize;
-use kanalang::Translator;
+use kanalang::dict::{Dictionary, Sense, Word, WordType};
+use kanalang::export::{Format, export};
+use kanalang::suggest::{did_you_mean, suggest};
+use kanalang::{Diagnostic, Lang, Note, Pivot, Severity, Translator};
 use std::env;
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, Read, Write};
+use std::process;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -24,16 +28,70 @@ fn print_usage() {
     println!("  {}", "$ echo \"i love you\" | kanalang to".bright_green());
     println!("  {}", "mi olin e sina".bright_black());
     println!();
+    println!(
+        "  {}",
+        "$ kanalang --lang es from \"mi wile e moku\"".bright_green()
+    );
+    println!("  {}", "yo quiero comida.".bright_black());
+    println!();
+    println!(
+        "  {}",
+        "$ kanalang pivot --from en --to pl \"i want food\"".bright_green()
+    );
+    println!("  {}", "kana  mi wile e moku".bright_black());
+    println!("  {}", "pl    ja chcę jedzenie.".bright_black());
+    println!();
+    println!("  {}", "$ kanalang lint \"jan moku e pan\"".bright_green());
+    println!(
+        "  {}",
+        "error: missing `li` after subject `jan`".bright_black()
+    );
+    println!();
     println!("{}", "COMMANDS:".white().underline());
     println!(
         "  {}  {}",
         "to, en2k".green().bold(),
-        "Translate English → Kana".white()
+        "Translate English (or --lang) → Kana".white()
     );
     println!(
         "  {}  {}",
         "from, k2en".green().bold(),
-        "Translate Kana → English".white()
+        "Translate Kana → English (or --lang)".white()
+    );
+    println!(
+        "  {}  {}",
+        "pivot --from <code> --to <code>".green().bold(),
+        "Translate between languages through Kana".white()
+    );
+    println!(
+        "  {}  {}",
+        "lint".green().bold(),
+        "Check Kana grammar and vocabulary".white()
+    );
+    println!(
+        "  {}  {}",
+        "dict check".green().bold(),
+        "Report duplicate and conflicting dictionary entries".white()
+    );
+    println!(
+        "  {}  {}",
+        "dict export --format <f>".green().bold(),
+        "Export as json, csv, markdown or anki-tsv".white()
+    );
+    println!(
+        "  {}  {}",
+        "define <kana>".green().bold(),
+        "Show a word's senses, examples and compounds".white()
+    );
+    println!(
+        "  {}  {}",
+        "search <word>".green().bold(),
+        "Find Kana words by gloss".white()
+    );
+    println!(
+        "  {}  {}",
+        "list [--type <type>]".green().bold(),
+        "List the vocabulary, optionally by word type".white()
     );
     println!(
         "  {}  {}",
@@ -41,17 +99,73 @@ fn print_usage() {
         "Auto-detect language".white()
     );
     println!();
+    println!("{}", "OPTIONS:".white().underline());
+    println!(
+        "  {}  {}",
+        "--lang <code>".green(),
+        "Translate to and from en, pl, es or de (default en)".white()
+    );
+    println!(
+        "  {}  {}",
+        "--dict <path>".green(),
+        "Use a dictionary file instead of the built-in one".white()
+    );
+    println!(
+        "  {}  {}",
+        "--glossary <path>".green(),
+        "Layer a glossary over the dictionary (repeatable)".white()
+    );
+    println!();
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
 
     if args.len() > 1 && (args[1] == "-h" || args[1] == "--help" || args[1] == "help") {
         print_usage();
         return;
     }
 
-    let translator = Translator::new();
+    let dict = load_dictionary(&mut args);
+
+    if args.len() > 1 && args[1] == "lint" {
+        let input = if args.len() > 2 {
+            args[2..].join(" ")
+        } else {
+            let mut input = String::new();
+            let _ = io::stdin().read_to_string(&mut input);
+            input
+        };
+        run_lint(&dict, &input);
+        return;
+    }
+
+    if args.len() > 1 && args[1] == "dict" {
+        run_dict(&dict, &args[2..]);
+        return;
+    }
+
+    if args.len() > 1 && args[1] == "define" {
+        run_define(&dict, &args[2..].join(" "));
+        return;
+    }
+
+    if args.len() > 1 && args[1] == "search" {
+        run_search(&dict, &args[2..].join(" "));
+        return;
+    }
+
+    if args.len() > 1 && args[1] == "list" {
+        run_list(&dict, &args[2..]);
+        return;
+    }
+
+    if args.len() > 1 && args[1] == "pivot" {
+        run_pivot(&dict, &args[2..]);
+        return;
+    }
+
+    let translator = Translator::with_dictionary(dict);
 
     let direction = if args.len() > 1 {
         match args[1].as_str() {
@@ -65,8 +179,9 @@ fn main() {
 
     if args.len() > 2 {
         let input = args[2..].join(" ");
-        let output = translator.translate(&input, direction);
-        println!("{}", output);
+        let output = translator.translate_annotated(&input, direction);
+        println!("{}", output.text);
+        print_notes(&output.notes);
         return;
     }
 
@@ -77,8 +192,10 @@ fn main() {
         for line in stdin.lock().lines() {
             match line {
                 Ok(text) => {
-                    output.push_str(&translator.translate(&text, direction));
+                    let translation = translator.translate_annotated(&text, direction);
+                    output.push_str(&translation.text);
                     output.push('\n');
+                    print_notes(&translation.notes);
                 }
                 Err(_) => break,
             }
@@ -95,11 +212,392 @@ fn main() {
         match line {
             Ok(text) => {
                 if !text.trim().is_empty() {
-                    let output = translator.translate(&text, direction);
-                    println!("{}", output);
+                    let output = translator.translate_annotated(&text, direction);
+                    println!("{}", output.text);
+                    print_notes(&output.notes);
                 }
             }
             Err(_) => break,
         }
     }
 }
+
+/// Removes `--lang <code>`, `--dict <path>` and any `--glossary <path>`
+/// options from `args` and loads that dictionary (or the built-in one for
+/// the language), with each glossary layered on top in order.
+fn load_dictionary(args: &mut Vec<String>) -> Dictionary {
+    let mut lang = Lang::English;
+    let mut dict_path = None;
+    let mut glossaries = Vec::new();
+
+    let mut i = 1;
+    while i < args.len() {
+        if !["--lang", "--dict", "--glossary"].contains(&args[i].as_str()) {
+            i += 1;
+            continue;
+        }
+        if i + 1 >= args.len() {
+            let value = if args[i] == "--lang" {
+                "a language"
+            } else {
+                "a path"
+            };
+            eprintln!("{} {} requires {}", "error:".red(), args[i], value);
+            process::exit(2);
+        }
+        let value = args.remove(i + 1);
+        match args.remove(i).as_str() {
+            "--lang" => match value.parse() {
+                Ok(parsed) => lang = parsed,
+                Err(err) => {
+                    eprintln!("{} {}", "error:".red(), err);
+                    process::exit(2);
+                }
+            },
+            "--dict" => dict_path = Some(value),
+            _ => glossaries.push(value),
+        }
+    }
+
+    let mut dict = match dict_path {
+        Some(path) => read_dictionary(&path).with_lang(lang),
+        None => Dictionary::builtin(lang),
+    };
+    for path in glossaries {
+        let glossary = read_dictionary(&path);
+        warn_glossary_lang(&path, &glossary, dict.lang());
+        dict = dict.overlay(path, &glossary);
+    }
+    dict
+}
+
+/// A glossary in another language would add glosses nobody types.
+fn warn_glossary_lang(path: &str, glossary: &Dictionary, lang: Lang) {
+    if let Some(found) = glossary.likely_lang()
+        && found != lang
+    {
+        eprintln!(
+            "{} {} has {} glosses, but the dictionary is {}",
+            "warning:".yellow().bold(),
+            path,
+            found.name(),
+            lang.name()
+        );
+    }
+}
+
+fn read_dictionary(path: &str) -> Dictionary {
+    match Dictionary::from_path(path) {
+        Ok(dict) => dict,
+        Err(err) => {
+            eprintln!("{} cannot load {}: {}", "error:".red(), path, err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Notes go to stderr so piped translations stay clean.
+fn print_notes(notes: &[Note]) {
+    for note in notes {
+        eprintln!("{} {}", "note:".cyan().bold(), note.message.dimmed());
+    }
+}
+
+/// `--from` defaults to the dictionary's language. Each side uses the loaded
+/// dictionary if it is in that side's language, else the built-in one.
+fn run_pivot(dict: &Dictionary, args: &[String]) {
+    let usage = || -> ! {
+        eprintln!(
+            "{} usage: kanalang pivot [--from <code>] --to <code> [text]",
+            "error:".red()
+        );
+        process::exit(2);
+    };
+    let mut from = dict.lang();
+    let mut to = None;
+    let mut rest = args;
+    while let [flag, value, tail @ ..] = rest {
+        let lang = match flag.as_str() {
+            "--from" | "--to" => match value.parse::<Lang>() {
+                Ok(lang) => lang,
+                Err(err) => {
+                    eprintln!("{} {}", "error:".red(), err);
+                    process::exit(2);
+                }
+            },
+            _ => break,
+        };
+        if flag == "--from" {
+            from = lang;
+        } else {
+            to = Some(lang);
+        }
+        rest = tail;
+    }
+    let Some(to) = to else { usage() };
+    if rest.first().is_some_and(|arg| arg.starts_with("--")) {
+        usage();
+    }
+
+    let translator = |lang: Lang| {
+        if lang == dict.lang() {
+            Translator::with_dictionary(dict.clone())
+        } else {
+            Translator::with_dictionary(Dictionary::builtin(lang))
+        }
+    };
+    let (source, target) = (translator(from), translator(to));
+
+    if !rest.is_empty() {
+        print_pivot(&source.pivot(&target, &rest.join(" ")), to);
+        return;
+    }
+    for line in io::stdin().lock().lines() {
+        match line {
+            Ok(text) if !text.trim().is_empty() => print_pivot(&source.pivot(&target, &text), to),
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+}
+
+/// The Kana intermediate above the target text, so both can be compared.
+fn print_pivot(pivot: &Pivot, to: Lang) {
+    let width = to.code().len().max("kana".len());
+    println!(
+        "{}  {}",
+        format!("{:<width$}", "kana").dimmed(),
+        pivot.kana.text.bright_green()
+    );
+    println!(
+        "{}  {}",
+        format!("{:<width$}", to.code()).dimmed(),
+        pivot.target.text
+    );
+    print_notes(&pivot.kana.notes);
+    print_notes(&pivot.target.notes);
+}
+
+fn run_dict(dict: &Dictionary, args: &[String]) {
+    match args.first().map(String::as_str) {
+        Some("check") => {
+            let issues = dict.validate();
+            for issue in &issues {
+                let label = match issue.severity {
+                    Severity::Error => "error".red().bold(),
+                    Severity::Warning => "warning".yellow().bold(),
+                };
+                println!("{}: {}", label, issue.message);
+            }
+
+            let errors = issues
+                .iter()
+                .filter(|i| i.severity == Severity::Error)
+                .count();
+            let warnings = issues.len() - errors;
+            println!(
+                "{}",
+                format!(
+                    "{} words, {} errors, {} warnings",
+                    dict.words().len(),
+                    errors,
+                    warnings
+                )
+                .dimmed()
+            );
+            if dict.layers().len() > 1 {
+                println!(
+                    "{}",
+                    format!("layers: {}", dict.layers().join(" < ")).dimmed()
+                );
+            }
+            if errors > 0 {
+                process::exit(1);
+            }
+        }
+        Some("export") => {
+            let format = match &args[1..] {
+                [flag, format] if flag == "--format" => format.parse::<Format>(),
+                _ => Err("usage: kanalang dict export --format <format>".to_string()),
+            };
+            match format {
+                Ok(format) => print!("{}", export(dict, format)),
+                Err(err) => {
+                    eprintln!("{} {}", "error:".red(), err);
+                    process::exit(2);
+                }
+            }
+        }
+        _ => {
+            eprintln!(
+                "{} usage: kanalang dict check | dict export --format <format>",
+                "error:".red()
+            );
+            process::exit(2);
+        }
+    }
+}
+
+fn run_define(dict: &Dictionary, kana: &str) {
+    let kana = kana.trim().to_lowercase();
+    let Some(word) = dict.entry(&kana) else {
+        eprintln!("{} `{}` is not in the dictionary", "error:".red(), kana);
+        if let Some(help) = did_you_mean(&suggest(dict, &kana)) {
+            eprintln!("  {} {}", "help:".cyan(), help);
+        }
+        process::exit(1);
+    };
+
+    let translator = Translator::with_dictionary(dict.clone());
+    println!("{}", word.kana.green().bold());
+    for (i, sense) in word.senses.iter().enumerate() {
+        // With glossaries, say which layer each sense came from.
+        let layer = match dict.sense_layer(&word.kana, i) {
+            Some(layer) if dict.layers().len() > 1 => format!(" ({})", layer),
+            _ => String::new(),
+        };
+        println!(
+            "  {:<8}  {}{}",
+            sense.wtype.name().cyan(),
+            sense.glosses.join(", ").white(),
+            layer.dimmed()
+        );
+        for example in sense.examples.iter() {
+            println!(
+                "  {:<8}  {} {}",
+                "",
+                example.bright_green(),
+                format!("— {}", translator.from_kana(example)).dimmed()
+            );
+        }
+    }
+
+    if word.kana.contains(' ') {
+        println!();
+        println!("{}", "parts:".white().underline());
+        let parts: Vec<&Word> = word.kana.split(' ').filter_map(|p| dict.entry(p)).collect();
+        print_words(&parts);
+    }
+
+    let compounds = dict.compounds_with(&word.kana);
+    if !compounds.is_empty() {
+        println!();
+        println!("{}", "compounds:".white().underline());
+        print_words(&compounds);
+    }
+}
+
+fn run_search(dict: &Dictionary, query: &str) {
+    if query.trim().is_empty() {
+        eprintln!("{} usage: kanalang search <word>", "error:".red());
+        process::exit(2);
+    }
+    let matches = dict.search(query);
+    if matches.is_empty() {
+        eprintln!("{} no glosses match `{}`", "error:".red(), query.trim());
+        process::exit(1);
+    }
+    print_senses(&matches);
+}
+
+fn run_list(dict: &Dictionary, args: &[String]) {
+    let senses: Vec<(&Word, &Sense)> = match args {
+        [] => dict
+            .words()
+            .iter()
+            .flat_map(|word| word.senses.iter().map(move |sense| (word, sense)))
+            .collect(),
+        [flag, wtype] if flag == "--type" => match wtype.parse::<WordType>() {
+            Ok(wtype) => dict.senses_of_type(wtype),
+            Err(err) => {
+                eprintln!("{} {}", "error:".red(), err);
+                process::exit(2);
+            }
+        },
+        _ => {
+            eprintln!("{} usage: kanalang list [--type <type>]", "error:".red());
+            process::exit(2);
+        }
+    };
+    print_senses(&senses);
+}
+
+/// One line per word, with its default sense.
+fn print_words(words: &[&Word]) {
+    let senses: Vec<(&Word, &Sense)> = words.iter().map(|w| (*w, w.primary())).collect();
+    print_senses(&senses);
+}
+
+/// One aligned line per sense: kana, type, glosses.
+fn print_senses(senses: &[(&Word, &Sense)]) {
+    let width = senses
+        .iter()
+        .map(|(word, _)| word.kana.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (word, sense) in senses {
+        println!(
+            "  {:<width$}  {:<8}  {}",
+            word.kana.green(),
+            sense.wtype.name().cyan(),
+            sense.glosses.join(", ").white(),
+            width = width
+        );
+    }
+}
+
+fn run_lint(dict: &Dictionary, input: &str) {
+    let diagnostics = kanalang::lint_with(dict, input);
+
+    for diagnostic in &diagnostics {
+        print_diagnostic(input, diagnostic);
+    }
+
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        process::exit(1);
+    }
+}
+
+fn print_diagnostic(input: &str, diagnostic: &Diagnostic) {
+    let label = match diagnostic.severity {
+        Severity::Error => "error".red().bold(),
+        Severity::Warning => "warning".yellow().bold(),
+    };
+    println!("{}: {}", label, diagnostic.message.white().bold());
+
+    let span = diagnostic.span;
+    let line_start = input[..span.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = input[span.start..]
+        .find('\n')
+        .map(|i| span.start + i)
+        .unwrap_or(input.len());
+    let line = span.line;
+    let column = span.column - 1;
+    let width = (span.char_end - span.char_start).max(1);
+
+    let gutter = line.to_string();
+    let pad = " ".repeat(gutter.len());
+    println!("{}{} {}:{}", pad, "-->".bright_blue(), line, span.column);
+    println!("{} {}", pad, "|".bright_blue());
+    println!(
+        "{} {} {}",
+        gutter.bright_blue(),
+        "|".bright_blue(),
+        &input[line_start..line_end]
+    );
+    println!(
+        "{} {} {}{}",
+        pad,
+        "|".bright_blue(),
+        " ".repeat(column),
+        "^".repeat(width).bright_red()
+    );
+    if let Some(help) = &diagnostic.help {
+        println!(
+            "{} {} {}",
+            pad,
+            "=".bright_blue(),
+            format!("help: {}", help).white()
+        );
+    }
+}
@@ -0,0 +1,527 @@
+//! Inflection.
+//!
+//! The dictionary only lists base forms ("eat", "house"), so inflected words
+//! are reduced to candidate lemmas before lookup. Each candidate keeps the
+//! tense and number that were stripped, for stages that want to express them.
+//! English has full rules; the other languages have a table of common
+//! irregular forms and a list of endings to try.
+
+use crate::lang::Lang;
+
+/// Tense carried by an English verb form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tense {
+    #[default]
+    Present,
+    /// "ate", "loved", "has eaten"
+    Past,
+    /// "eating"
+    Progressive,
+}
+
+/// Number carried by an English noun form. A verb ending in `-s` ("eats")
+/// is also reported as `Plural`; only the dictionary knows which it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Number {
+    #[default]
+    Singular,
+    Plural,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lemma {
+    pub base: String,
+    pub tense: Tense,
+    pub number: Number,
+}
+
+impl Lemma {
+    pub fn new(base: &str, tense: Tense, number: Number) -> Self {
+        Lemma {
+            base: base.to_string(),
+            tense,
+            number,
+        }
+    }
+}
+
+/// Irregular forms: (form, base, tense, number).
+const IRREGULAR: &[(&str, &str, Tense, Number)] = &[
+    ("am", "be", Tense::Present, Number::Singular),
+    ("is", "be", Tense::Present, Number::Singular),
+    ("are", "be", Tense::Present, Number::Plural),
+    ("was", "be", Tense::Past, Number::Singular),
+    ("were", "be", Tense::Past, Number::Plural),
+    ("been", "be", Tense::Past, Number::Singular),
+    ("being", "be", Tense::Progressive, Number::Singular),
+    ("has", "have", Tense::Present, Number::Singular),
+    ("had", "have", Tense::Past, Number::Singular),
+    ("does", "do", Tense::Present, Number::Singular),
+    ("did", "do", Tense::Past, Number::Singular),
+    ("done", "do", Tense::Past, Number::Singular),
+    ("goes", "go", Tense::Present, Number::Singular),
+    ("went", "go", Tense::Past, Number::Singular),
+    ("gone", "go", Tense::Past, Number::Singular),
+    ("ate", "eat", Tense::Past, Number::Singular),
+    ("eaten", "eat", Tense::Past, Number::Singular),
+    ("saw", "see", Tense::Past, Number::Singular),
+    ("seen", "see", Tense::Past, Number::Singular),
+    ("came", "come", Tense::Past, Number::Singular),
+    ("gave", "give", Tense::Past, Number::Singular),
+    ("given", "give", Tense::Past, Number::Singular),
+    ("made", "make", Tense::Past, Number::Singular),
+    ("said", "say", Tense::Past, Number::Singular),
+    ("took", "take", Tense::Past, Number::Singular),
+    ("taken", "take", Tense::Past, Number::Singular),
+    ("knew", "know", Tense::Past, Number::Singular),
+    ("known", "know", Tense::Past, Number::Singular),
+    ("thought", "think", Tense::Past, Number::Singular),
+    ("found", "find", Tense::Past, Number::Singular),
+    ("told", "tell", Tense::Past, Number::Singular),
+    ("felt", "feel", Tense::Past, Number::Singular),
+    ("left", "leave", Tense::Past, Number::Singular),
+    ("heard", "hear", Tense::Past, Number::Singular),
+    ("spoke", "speak", Tense::Past, Number::Singular),
+    ("spoken", "speak", Tense::Past, Number::Singular),
+    ("slept", "sleep", Tense::Past, Number::Singular),
+    ("ran", "run", Tense::Past, Number::Singular),
+    ("bought", "buy", Tense::Past, Number::Singular),
+    ("brought", "bring", Tense::Past, Number::Singular),
+    ("sat", "sit", Tense::Past, Number::Singular),
+    ("stood", "stand", Tense::Past, Number::Singular),
+    ("understood", "understand", Tense::Past, Number::Singular),
+    ("wrote", "write", Tense::Past, Number::Singular),
+    ("written", "write", Tense::Past, Number::Singular),
+    ("drank", "drink", Tense::Past, Number::Singular),
+    ("drunk", "drink", Tense::Past, Number::Singular),
+    ("fought", "fight", Tense::Past, Number::Singular),
+    ("began", "begin", Tense::Past, Number::Singular),
+    ("begun", "begin", Tense::Past, Number::Singular),
+    ("grew", "grow", Tense::Past, Number::Singular),
+    ("grown", "grow", Tense::Past, Number::Singular),
+    ("held", "hold", Tense::Past, Number::Singular),
+    ("kept", "keep", Tense::Past, Number::Singular),
+    ("lost", "lose", Tense::Past, Number::Singular),
+    ("met", "meet", Tense::Past, Number::Singular),
+    ("paid", "pay", Tense::Past, Number::Singular),
+    ("sent", "send", Tense::Past, Number::Singular),
+    ("built", "build", Tense::Past, Number::Singular),
+    ("fell", "fall", Tense::Past, Number::Singular),
+    ("fallen", "fall", Tense::Past, Number::Singular),
+    ("flew", "fly", Tense::Past, Number::Singular),
+    ("flown", "fly", Tense::Past, Number::Singular),
+    ("forgot", "forget", Tense::Past, Number::Singular),
+    ("forgotten", "forget", Tense::Past, Number::Singular),
+    ("got", "get", Tense::Past, Number::Singular),
+    ("gotten", "get", Tense::Past, Number::Singular),
+    ("broke", "break", Tense::Past, Number::Singular),
+    ("broken", "break", Tense::Past, Number::Singular),
+    ("chose", "choose", Tense::Past, Number::Singular),
+    ("chosen", "choose", Tense::Past, Number::Singular),
+    ("drove", "drive", Tense::Past, Number::Singular),
+    ("driven", "drive", Tense::Past, Number::Singular),
+    ("sang", "sing", Tense::Past, Number::Singular),
+    ("sung", "sing", Tense::Past, Number::Singular),
+    ("sold", "sell", Tense::Past, Number::Singular),
+    ("taught", "teach", Tense::Past, Number::Singular),
+    ("threw", "throw", Tense::Past, Number::Singular),
+    ("thrown", "throw", Tense::Past, Number::Singular),
+    ("woke", "wake", Tense::Past, Number::Singular),
+    ("wore", "wear", Tense::Past, Number::Singular),
+    ("won", "win", Tense::Past, Number::Singular),
+    ("swam", "swim", Tense::Past, Number::Singular),
+    ("lying", "lie", Tense::Progressive, Number::Singular),
+    ("dying", "die", Tense::Progressive, Number::Singular),
+    ("died", "die", Tense::Past, Number::Singular),
+    ("doing", "do", Tense::Progressive, Number::Singular),
+    ("going", "go", Tense::Progressive, Number::Singular),
+    ("used", "use", Tense::Past, Number::Singular),
+    ("using", "use", Tense::Progressive, Number::Singular),
+    ("people", "person", Tense::Present, Number::Plural),
+    ("men", "man", Tense::Present, Number::Plural),
+    ("women", "woman", Tense::Present, Number::Plural),
+    ("children", "child", Tense::Present, Number::Plural),
+    ("mice", "mouse", Tense::Present, Number::Plural),
+    ("feet", "foot", Tense::Present, Number::Plural),
+    ("teeth", "tooth", Tense::Present, Number::Plural),
+    ("geese", "goose", Tense::Present, Number::Plural),
+    ("leaves", "leaf", Tense::Present, Number::Plural),
+    ("knives", "knife", Tense::Present, Number::Plural),
+    ("lives", "life", Tense::Present, Number::Plural),
+    ("wives", "wife", Tense::Present, Number::Plural),
+    ("wolves", "wolf", Tense::Present, Number::Plural),
+    ("halves", "half", Tense::Present, Number::Plural),
+];
+
+/// Candidate lemmas for `word`, most likely first. The word itself always
+/// comes first; suffix rules may produce stems that are not words, so
+/// callers keep the first candidate their dictionary knows.
+pub fn lemmas(word: &str) -> Vec<Lemma> {
+    let word = word.to_lowercase();
+    let mut candidates = vec![Lemma::new(&word, Tense::Present, Number::Singular)];
+
+    for &(form, base, tense, number) in IRREGULAR {
+        if form == word {
+            candidates.push(Lemma::new(base, tense, number));
+        }
+    }
+
+    let mut push = |base: &str, tense, number| {
+        if base.len() >= 2 && !candidates.iter().any(|c| c.base == base) {
+            candidates.push(Lemma::new(base, tense, number));
+        }
+    };
+
+    if let Some(stem) = word.strip_suffix("ies") {
+        push(&format!("{}y", stem), Tense::Present, Number::Plural);
+    }
+    if let Some(stem) = word.strip_suffix("es")
+        && ["s", "x", "z", "ch", "sh", "o"]
+            .iter()
+            .any(|end| stem.ends_with(end))
+    {
+        push(stem, Tense::Present, Number::Plural);
+    }
+    // Three letters at least, so "is" and "us" don't become "i" and "u".
+    if let Some(stem) = word.strip_suffix('s')
+        && stem.len() >= 3
+        && !stem.ends_with('s')
+        && !stem.ends_with('u')
+    {
+        push(stem, Tense::Present, Number::Plural);
+    }
+
+    if let Some(stem) = word.strip_suffix("ied") {
+        push(&format!("{}y", stem), Tense::Past, Number::Singular);
+    }
+    // A real stem at least, so "seed", "shed" and "wing" stay themselves
+    // rather than "see", "she" and "w"; short verbs are in `IRREGULAR`.
+    let is_stem = |stem: &str| stem.len() >= 3 && stem.contains(['a', 'e', 'i', 'o', 'u', 'y']);
+    if let Some(stem) = word.strip_suffix("ed")
+        && is_stem(stem)
+    {
+        push(&format!("{}e", stem), Tense::Past, Number::Singular);
+        push(stem, Tense::Past, Number::Singular);
+        if let Some(undoubled) = undouble(stem) {
+            push(undoubled, Tense::Past, Number::Singular);
+        }
+    }
+
+    if let Some(stem) = word.strip_suffix("ing")
+        && is_stem(stem)
+    {
+        push(stem, Tense::Progressive, Number::Singular);
+        push(&format!("{}e", stem), Tense::Progressive, Number::Singular);
+        if let Some(undoubled) = undouble(stem) {
+            push(undoubled, Tense::Progressive, Number::Singular);
+        }
+    }
+
+    candidates
+}
+
+/// Like [`lemmas`], for a word in `lang`.
+pub fn lemmas_in(lang: Lang, word: &str) -> Vec<Lemma> {
+    let (irregular, endings) = match lang {
+        Lang::English => return lemmas(word),
+        Lang::Polish => (POLISH_IRREGULAR, POLISH_ENDINGS),
+        Lang::Spanish => (SPANISH_IRREGULAR, SPANISH_ENDINGS),
+        Lang::German => (GERMAN_IRREGULAR, GERMAN_ENDINGS),
+    };
+
+    let word = word.to_lowercase();
+    let mut candidates = vec![Lemma::new(&word, Tense::Present, Number::Singular)];
+    for &(form, base, tense, number) in irregular {
+        if form == word {
+            candidates.push(Lemma::new(base, tense, number));
+        }
+    }
+    for &(ending, replacement, tense, number) in endings {
+        let Some(stem) = word.strip_suffix(ending) else {
+            continue;
+        };
+        let base = format!("{}{}", stem, replacement);
+        if stem.chars().count() >= 2 && !candidates.iter().any(|c| c.base == base) {
+            candidates.push(Lemma::new(&base, tense, number));
+        }
+    }
+    // geliebt → lieben
+    if lang == Lang::German
+        && let Some(stem) = word
+            .strip_prefix("ge")
+            .and_then(|rest| rest.strip_suffix('t'))
+        && stem.chars().count() >= 3
+    {
+        candidates.push(Lemma::new(
+            &format!("{}en", stem),
+            Tense::Past,
+            Number::Singular,
+        ));
+    }
+    candidates
+}
+
+const POLISH_IRREGULAR: &[(&str, &str, Tense, Number)] = &[
+    ("jestem", "być", Tense::Present, Number::Singular),
+    ("jesteś", "być", Tense::Present, Number::Singular),
+    ("jest", "być", Tense::Present, Number::Singular),
+    ("jesteśmy", "być", Tense::Present, Number::Plural),
+    ("są", "być", Tense::Present, Number::Plural),
+    ("był", "być", Tense::Past, Number::Singular),
+    ("była", "być", Tense::Past, Number::Singular),
+    ("mam", "mieć", Tense::Present, Number::Singular),
+    ("masz", "mieć", Tense::Present, Number::Singular),
+    ("ma", "mieć", Tense::Present, Number::Singular),
+    ("mamy", "mieć", Tense::Present, Number::Plural),
+    ("mają", "mieć", Tense::Present, Number::Plural),
+    ("chcę", "chcieć", Tense::Present, Number::Singular),
+    ("chcesz", "chcieć", Tense::Present, Number::Singular),
+    ("chce", "chcieć", Tense::Present, Number::Singular),
+    ("chcemy", "chcieć", Tense::Present, Number::Plural),
+    ("chcą", "chcieć", Tense::Present, Number::Plural),
+    ("jem", "jeść", Tense::Present, Number::Singular),
+    ("jesz", "jeść", Tense::Present, Number::Singular),
+    ("je", "jeść", Tense::Present, Number::Singular),
+    ("jemy", "jeść", Tense::Present, Number::Plural),
+    ("jedzą", "jeść", Tense::Present, Number::Plural),
+    ("idę", "iść", Tense::Present, Number::Singular),
+    ("idziesz", "iść", Tense::Present, Number::Singular),
+    ("idzie", "iść", Tense::Present, Number::Singular),
+    ("idziemy", "iść", Tense::Present, Number::Plural),
+    ("idą", "iść", Tense::Present, Number::Plural),
+    ("wiem", "wiedzieć", Tense::Present, Number::Singular),
+    ("wiesz", "wiedzieć", Tense::Present, Number::Singular),
+    ("wie", "wiedzieć", Tense::Present, Number::Singular),
+    ("śpię", "spać", Tense::Present, Number::Singular),
+    ("śpisz", "spać", Tense::Present, Number::Singular),
+    ("śpi", "spać", Tense::Present, Number::Singular),
+    ("mogę", "móc", Tense::Present, Number::Singular),
+    ("możesz", "móc", Tense::Present, Number::Singular),
+    ("może", "móc", Tense::Present, Number::Singular),
+    ("piję", "pić", Tense::Present, Number::Singular),
+    ("pije", "pić", Tense::Present, Number::Singular),
+    ("daję", "dawać", Tense::Present, Number::Singular),
+    ("daje", "dawać", Tense::Present, Number::Singular),
+    ("ludzie", "człowiek", Tense::Present, Number::Plural),
+    ("dzieci", "dziecko", Tense::Present, Number::Plural),
+];
+
+/// (ending, replacement, tense, number), tried in order.
+const POLISH_ENDINGS: &[(&str, &str, Tense, Number)] = &[
+    // kocham, kochasz, kocha, kochamy, kochają → kochać
+    ("am", "ać", Tense::Present, Number::Singular),
+    ("asz", "ać", Tense::Present, Number::Singular),
+    ("a", "ać", Tense::Present, Number::Singular),
+    ("amy", "ać", Tense::Present, Number::Plural),
+    ("ają", "ać", Tense::Present, Number::Plural),
+    // mówię, mówisz, mówi → mówić; widzę, widzisz → widzieć
+    ("ię", "ić", Tense::Present, Number::Singular),
+    ("ę", "ić", Tense::Present, Number::Singular),
+    ("ę", "eć", Tense::Present, Number::Singular),
+    ("ę", "ieć", Tense::Present, Number::Singular),
+    ("isz", "ić", Tense::Present, Number::Singular),
+    ("isz", "ieć", Tense::Present, Number::Singular),
+    ("ysz", "eć", Tense::Present, Number::Singular),
+    ("i", "ić", Tense::Present, Number::Singular),
+    ("i", "ieć", Tense::Present, Number::Singular),
+    ("y", "eć", Tense::Present, Number::Singular),
+    ("imy", "ić", Tense::Present, Number::Plural),
+    ("ą", "ić", Tense::Present, Number::Plural),
+    // pracuję, pracuje → pracować
+    ("uję", "ować", Tense::Present, Number::Singular),
+    ("uje", "ować", Tense::Present, Number::Singular),
+    // wodę → woda, domu → dom, domy → dom, dobra → dobry
+    ("ę", "a", Tense::Present, Number::Singular),
+    ("y", "a", Tense::Present, Number::Singular),
+    ("u", "", Tense::Present, Number::Singular),
+    ("em", "", Tense::Present, Number::Singular),
+    ("y", "", Tense::Present, Number::Plural),
+    ("i", "", Tense::Present, Number::Plural),
+    ("ów", "", Tense::Present, Number::Plural),
+    ("ami", "", Tense::Present, Number::Plural),
+    ("a", "y", Tense::Present, Number::Singular),
+    ("e", "y", Tense::Present, Number::Singular),
+    ("ego", "y", Tense::Present, Number::Singular),
+    ("ą", "y", Tense::Present, Number::Singular),
+    ("e", "i", Tense::Present, Number::Singular),
+];
+
+const SPANISH_IRREGULAR: &[(&str, &str, Tense, Number)] = &[
+    ("soy", "ser", Tense::Present, Number::Singular),
+    ("eres", "ser", Tense::Present, Number::Singular),
+    ("es", "ser", Tense::Present, Number::Singular),
+    ("somos", "ser", Tense::Present, Number::Plural),
+    ("son", "ser", Tense::Present, Number::Plural),
+    ("estoy", "estar", Tense::Present, Number::Singular),
+    ("estás", "estar", Tense::Present, Number::Singular),
+    ("está", "estar", Tense::Present, Number::Singular),
+    ("están", "estar", Tense::Present, Number::Plural),
+    ("tengo", "tener", Tense::Present, Number::Singular),
+    ("tienes", "tener", Tense::Present, Number::Singular),
+    ("tiene", "tener", Tense::Present, Number::Singular),
+    ("tienen", "tener", Tense::Present, Number::Plural),
+    ("quiero", "querer", Tense::Present, Number::Singular),
+    ("quieres", "querer", Tense::Present, Number::Singular),
+    ("quiere", "querer", Tense::Present, Number::Singular),
+    ("quieren", "querer", Tense::Present, Number::Plural),
+    ("puedo", "poder", Tense::Present, Number::Singular),
+    ("puedes", "poder", Tense::Present, Number::Singular),
+    ("puede", "poder", Tense::Present, Number::Singular),
+    ("voy", "ir", Tense::Present, Number::Singular),
+    ("vas", "ir", Tense::Present, Number::Singular),
+    ("va", "ir", Tense::Present, Number::Singular),
+    ("vamos", "ir", Tense::Present, Number::Plural),
+    ("van", "ir", Tense::Present, Number::Plural),
+    ("vengo", "venir", Tense::Present, Number::Singular),
+    ("viene", "venir", Tense::Present, Number::Singular),
+    ("hago", "hacer", Tense::Present, Number::Singular),
+    ("digo", "decir", Tense::Present, Number::Singular),
+    ("dice", "decir", Tense::Present, Number::Singular),
+    ("sé", "saber", Tense::Present, Number::Singular),
+    ("duermo", "dormir", Tense::Present, Number::Singular),
+    ("duerme", "dormir", Tense::Present, Number::Singular),
+    ("doy", "dar", Tense::Present, Number::Singular),
+    ("veo", "ver", Tense::Present, Number::Singular),
+    ("ves", "ver", Tense::Present, Number::Singular),
+    ("ve", "ver", Tense::Present, Number::Singular),
+    ("vemos", "ver", Tense::Present, Number::Plural),
+    ("siento", "sentir", Tense::Present, Number::Singular),
+    ("siente", "sentir", Tense::Present, Number::Singular),
+];
+
+const SPANISH_ENDINGS: &[(&str, &str, Tense, Number)] = &[
+    // hablo, hablas, habla, hablamos, hablan → hablar; como → comer; vivo → vivir
+    ("o", "ar", Tense::Present, Number::Singular),
+    ("o", "er", Tense::Present, Number::Singular),
+    ("o", "ir", Tense::Present, Number::Singular),
+    ("as", "ar", Tense::Present, Number::Singular),
+    ("a", "ar", Tense::Present, Number::Singular),
+    ("amos", "ar", Tense::Present, Number::Plural),
+    ("an", "ar", Tense::Present, Number::Plural),
+    ("es", "er", Tense::Present, Number::Singular),
+    ("es", "ir", Tense::Present, Number::Singular),
+    ("e", "er", Tense::Present, Number::Singular),
+    ("e", "ir", Tense::Present, Number::Singular),
+    ("emos", "er", Tense::Present, Number::Plural),
+    ("imos", "ir", Tense::Present, Number::Plural),
+    ("en", "er", Tense::Present, Number::Plural),
+    ("en", "ir", Tense::Present, Number::Plural),
+    // casas → casa, flores → flor, buena → bueno
+    ("s", "", Tense::Present, Number::Plural),
+    ("es", "", Tense::Present, Number::Plural),
+    ("a", "o", Tense::Present, Number::Singular),
+    ("as", "o", Tense::Present, Number::Plural),
+    ("os", "o", Tense::Present, Number::Plural),
+];
+
+const GERMAN_IRREGULAR: &[(&str, &str, Tense, Number)] = &[
+    ("bin", "sein", Tense::Present, Number::Singular),
+    ("bist", "sein", Tense::Present, Number::Singular),
+    ("ist", "sein", Tense::Present, Number::Singular),
+    ("sind", "sein", Tense::Present, Number::Plural),
+    ("seid", "sein", Tense::Present, Number::Plural),
+    ("war", "sein", Tense::Past, Number::Singular),
+    ("habe", "haben", Tense::Present, Number::Singular),
+    ("hast", "haben", Tense::Present, Number::Singular),
+    ("hat", "haben", Tense::Present, Number::Singular),
+    ("will", "wollen", Tense::Present, Number::Singular),
+    ("willst", "wollen", Tense::Present, Number::Singular),
+    ("kann", "können", Tense::Present, Number::Singular),
+    ("kannst", "können", Tense::Present, Number::Singular),
+    ("muss", "müssen", Tense::Present, Number::Singular),
+    ("weiß", "wissen", Tense::Present, Number::Singular),
+    ("weißt", "wissen", Tense::Present, Number::Singular),
+    ("isst", "essen", Tense::Present, Number::Singular),
+    ("siehst", "sehen", Tense::Present, Number::Singular),
+    ("sieht", "sehen", Tense::Present, Number::Singular),
+    ("gibst", "geben", Tense::Present, Number::Singular),
+    ("gibt", "geben", Tense::Present, Number::Singular),
+    ("sprichst", "sprechen", Tense::Present, Number::Singular),
+    ("spricht", "sprechen", Tense::Present, Number::Singular),
+    ("nimmst", "nehmen", Tense::Present, Number::Singular),
+    ("nimmt", "nehmen", Tense::Present, Number::Singular),
+    ("schläfst", "schlafen", Tense::Present, Number::Singular),
+    ("schläft", "schlafen", Tense::Present, Number::Singular),
+    ("mag", "mögen", Tense::Present, Number::Singular),
+    ("magst", "mögen", Tense::Present, Number::Singular),
+    ("aß", "essen", Tense::Past, Number::Singular),
+    ("ging", "gehen", Tense::Past, Number::Singular),
+    ("kam", "kommen", Tense::Past, Number::Singular),
+];
+
+const GERMAN_ENDINGS: &[(&str, &str, Tense, Number)] = &[
+    // liebe, liebst, liebt → lieben; geliebt → lieben
+    ("e", "en", Tense::Present, Number::Singular),
+    ("st", "en", Tense::Present, Number::Singular),
+    ("t", "en", Tense::Present, Number::Singular),
+    ("et", "en", Tense::Present, Number::Plural),
+    ("te", "en", Tense::Past, Number::Singular),
+    // Tische → Tisch, Frauen → Frau, Kinder → Kind, Autos → Auto
+    ("e", "", Tense::Present, Number::Plural),
+    ("en", "", Tense::Present, Number::Plural),
+    ("n", "", Tense::Present, Number::Plural),
+    ("er", "", Tense::Present, Number::Plural),
+    ("s", "", Tense::Present, Number::Plural),
+    // gute, guten, gutes → gut
+    ("es", "", Tense::Present, Number::Singular),
+    ("em", "", Tense::Present, Number::Singular),
+];
+
+/// "stopp" → "stop", "runn" → "run".
+fn undouble(stem: &str) -> Option<&str> {
+    let mut chars = stem.chars().rev();
+    let last = chars.next()?;
+    let before = chars.next()?;
+    if last == before && !"aeiou".contains(last) {
+        Some(&stem[..stem.len() - last.len_utf8()])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bases(lang: Lang, word: &str) -> Vec<String> {
+        lemmas_in(lang, word).into_iter().map(|l| l.base).collect()
+    }
+
+    #[test]
+    fn the_word_itself_comes_first() {
+        assert_eq!(
+            lemmas("Eat"),
+            [Lemma::new("eat", Tense::Present, Number::Singular)]
+        );
+    }
+
+    #[test]
+    fn english_suffixes_and_irregular_forms() {
+        assert!(lemmas("ate").contains(&Lemma::new("eat", Tense::Past, Number::Singular)));
+        assert!(lemmas("eating").contains(&Lemma::new(
+            "eat",
+            Tense::Progressive,
+            Number::Singular
+        )));
+        assert!(lemmas("loved").contains(&Lemma::new("love", Tense::Past, Number::Singular)));
+        assert!(lemmas("stopped").contains(&Lemma::new("stop", Tense::Past, Number::Singular)));
+        assert!(lemmas("tries").contains(&Lemma::new("try", Tense::Present, Number::Plural)));
+        assert!(lemmas("boxes").contains(&Lemma::new("box", Tense::Present, Number::Plural)));
+        assert!(lemmas("going").contains(&Lemma::new("go", Tense::Progressive, Number::Singular)));
+    }
+
+    #[test]
+    fn short_words_are_not_stripped() {
+        for word in ["seed", "shed", "wed", "wing", "bring", "us"] {
+            assert_eq!(bases(Lang::English, word), [word], "{}", word);
+        }
+    }
+
+    #[test]
+    fn other_languages_use_their_endings() {
+        assert!(bases(Lang::Spanish, "como").contains(&"comer".to_string()));
+        assert!(bases(Lang::Polish, "kocham").contains(&"kochać".to_string()));
+        assert!(bases(Lang::German, "liebst").contains(&"lieben".to_string()));
+        assert!(bases(Lang::German, "geliebt").contains(&"lieben".to_string()));
+    }
+}
@@ -1,9 +1,92 @@
+use crate::ast::{Clause, Modifier, Phrase, Predicate, Prepositional, Sentence};
+use crate::lang::Lang;
+use std::fmt;
+use std::ops::Range;
+
+/// Location of a token in the original input. Offsets are zero-based and
+/// end-exclusive; `line` and `column` are one-based, with columns in chars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        Span {
+            start: 0,
+            end: 0,
+            char_start: 0,
+            char_end: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+impl Span {
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// An empty span right after this one, used to point at end of input.
+    pub fn after(&self) -> Span {
+        Span {
+            start: self.end,
+            end: self.end,
+            char_start: self.char_end,
+            char_end: self.char_end,
+            line: self.line,
+            column: self.column + (self.char_end - self.char_start),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
-pub enum Token {
+pub enum TokenKind {
     Word(String),
     Punctuation(char),
+    /// English possessive `'s` (or a bare trailing `'`).
+    Possessive,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+impl Token {
+    pub fn word(&self) -> Option<&str> {
+        match &self.kind {
+            TokenKind::Word(word) => Some(word),
+            TokenKind::Punctuation(_) | TokenKind::Possessive => None,
+        }
+    }
+}
+
+/// One sentence worth of tokens, without its closing punctuation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<'a> {
+    pub tokens: &'a [Token],
+    pub terminator: Option<char>,
 }
 
+impl Segment<'_> {
+    /// The source text the segment was tokenized from, without the terminator.
+    pub fn text<'s>(&self, input: &'s str) -> &'s str {
+        match (self.tokens.first(), self.tokens.last()) {
+            (Some(first), Some(last)) => &input[first.span.start..last.span.end],
+            _ => "",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
 pub struct Parser;
 
 impl Parser {
@@ -11,37 +94,737 @@ impl Parser {
         Parser
     }
 
+    /// Splits `input` into words and punctuation, each with its [`Span`].
     pub fn tokenize(&self, input: &str) -> Vec<Token> {
         let mut tokens = Vec::new();
         let mut current_word = String::new();
+        let mut word_start = Span::default();
+        let mut char_index = 0;
+        let mut line = 1;
+        let mut column = 1;
+
+        for (i, ch) in input.char_indices() {
+            let here = Span {
+                start: i,
+                end: i + ch.len_utf8(),
+                char_start: char_index,
+                char_end: char_index + 1,
+                line,
+                column,
+            };
 
-        for ch in input.chars() {
-            if ch.is_whitespace() {
+            if ch.is_whitespace() || is_punctuation(ch) {
                 if !current_word.is_empty() {
-                    tokens.push(Token::Word(current_word.clone()));
-                    current_word.clear();
+                    let span = Span {
+                        end: i,
+                        char_end: char_index,
+                        ..word_start
+                    };
+                    tokens.push(Token {
+                        kind: TokenKind::Word(std::mem::take(&mut current_word)),
+                        span,
+                    });
                 }
-            } else if ch == '.' || ch == ',' || ch == '?' || ch == '!' || ch == ':' || ch == ';' {
-                if !current_word.is_empty() {
-                    tokens.push(Token::Word(current_word.clone()));
-                    current_word.clear();
+                if is_punctuation(ch) {
+                    tokens.push(Token {
+                        kind: TokenKind::Punctuation(ch),
+                        span: here,
+                    });
                 }
-                tokens.push(Token::Punctuation(ch));
             } else {
+                if current_word.is_empty() {
+                    word_start = here;
+                }
                 current_word.push(ch);
             }
+
+            char_index += 1;
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
         }
 
         if !current_word.is_empty() {
-            tokens.push(Token::Word(current_word));
+            let span = Span {
+                end: input.len(),
+                char_end: char_index,
+                ..word_start
+            };
+            tokens.push(Token {
+                kind: TokenKind::Word(current_word),
+                span,
+            });
+        }
+
+        tokens
+    }
+
+    /// Splits tokens into sentences ending in `.`, `?`, `!` or `;`.
+    /// Segments without any words (as in "...") are dropped.
+    pub fn sentences<'a>(&self, tokens: &'a [Token]) -> Vec<Segment<'a>> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+
+        for (i, token) in tokens.iter().enumerate() {
+            if let TokenKind::Punctuation(ch @ ('.' | '?' | '!' | ';')) = token.kind {
+                segments.push(Segment {
+                    tokens: &tokens[start..i],
+                    terminator: Some(ch),
+                });
+                start = i + 1;
+            }
+        }
+        if start < tokens.len() {
+            segments.push(Segment {
+                tokens: &tokens[start..],
+                terminator: None,
+            });
+        }
+
+        segments.retain(|segment| segment.tokens.iter().any(|t| t.word().is_some()));
+        segments
+    }
+
+    /// Tokenizes English text, expanding contractions ("I'm" → "I am",
+    /// "can't" → "can not") and splitting possessive `'s` off into
+    /// [`TokenKind::Possessive`]. Expanded tokens share the original word's span.
+    pub fn tokenize_english(&self, input: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        for token in self.tokenize(input) {
+            match token.word() {
+                Some(word) => expand_contraction(word, token.span, &mut tokens),
+                None => tokens.push(token),
+            }
         }
+        tokens
+    }
 
+    /// Tokenizes text in `lang`, splitting its contractions ("al" → "a el",
+    /// "im" → "in dem"). Expanded tokens share the original word's span.
+    pub fn tokenize_in(&self, lang: Lang, input: &str) -> Vec<Token> {
+        if lang == Lang::English {
+            return self.tokenize_english(input);
+        }
+        let rules = lang.rules();
+        let mut tokens = Vec::new();
+        for token in self.tokenize(input) {
+            let parts = token
+                .word()
+                .and_then(|word| rules.contraction(&word.to_lowercase()));
+            match parts {
+                Some(parts) => tokens.extend(parts.iter().map(|part| Token {
+                    kind: TokenKind::Word(part.to_string()),
+                    span: token.span,
+                })),
+                None => tokens.push(token),
+            }
+        }
         tokens
     }
 }
 
+/// Includes the Spanish opening marks `¿` and `¡`, which never end a sentence.
+fn is_punctuation(ch: char) -> bool {
+    matches!(ch, '.' | ',' | '?' | '!' | ':' | ';' | '¿' | '¡')
+}
+
+/// Pronouns whose `'s` means "is" rather than possession.
+const IS_CONTRACTIONS: &[&str] = &[
+    "it", "he", "she", "that", "this", "what", "there", "here", "who", "where", "how",
+];
+
+fn expand_contraction(word: &str, span: Span, tokens: &mut Vec<Token>) {
+    let push = |tokens: &mut Vec<Token>, kind: TokenKind, span: Span| {
+        tokens.push(Token { kind, span });
+    };
+    let word_token = |text: &str| TokenKind::Word(text.to_string());
+
+    let Some((at, apostrophe)) = word
+        .char_indices()
+        .rfind(|(_, ch)| *ch == '\'' || *ch == '’')
+    else {
+        push(tokens, word_token(word), span);
+        return;
+    };
+    if at == 0 {
+        push(tokens, word_token(word), span);
+        return;
+    }
+
+    let stem = &word[..at];
+    let suffix = word[at + apostrophe.len_utf8()..].to_lowercase();
+    let lower_stem = stem.to_lowercase();
+
+    if suffix == "t" && lower_stem.ends_with('n') && stem.len() > 1 {
+        let base = &stem[..stem.len() - 1];
+        let (first, rest) = split_span(span, word, base.len());
+        let base = match base.to_lowercase().as_str() {
+            "ca" => "can".to_string(),
+            "wo" => "will".to_string(),
+            "sha" => "shall".to_string(),
+            "ai" => "is".to_string(),
+            _ => base.to_string(),
+        };
+        push(tokens, TokenKind::Word(base), first);
+        push(tokens, word_token("not"), rest);
+        return;
+    }
+
+    let (first, rest) = split_span(span, word, at);
+    let expansion = match suffix.as_str() {
+        "m" => Some("am"),
+        "re" => Some("are"),
+        "ve" => Some("have"),
+        "ll" => Some("will"),
+        "d" => Some("would"),
+        "s" if IS_CONTRACTIONS.contains(&lower_stem.as_str()) => Some("is"),
+        _ => None,
+    };
+
+    match (expansion, suffix.as_str()) {
+        (Some(expansion), _) => {
+            push(tokens, word_token(stem), first);
+            push(tokens, word_token(expansion), rest);
+        }
+        // "let's" is neither "let is" nor a possessive, and Kana has no
+        // word for "let us".
+        (None, "s") if lower_stem == "let" => push(tokens, word_token(word), span),
+        (None, "s" | "") => {
+            push(tokens, word_token(stem), first);
+            push(tokens, TokenKind::Possessive, rest);
+        }
+        _ => push(tokens, word_token(word), span),
+    }
+}
+
+/// Splits a word's span at byte offset `at` within `word`.
+fn split_span(span: Span, word: &str, at: usize) -> (Span, Span) {
+    let chars = word[..at].chars().count();
+    let first = Span {
+        end: span.start + at,
+        char_end: span.char_start + chars,
+        ..span
+    };
+    let rest = Span {
+        start: span.start + at,
+        char_start: span.char_start + chars,
+        column: span.column + chars,
+        ..span
+    };
+    (first, rest)
+}
+
 impl Default for Parser {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Words with a fixed grammatical role in a Kana sentence.
+pub const MARKERS: &[&str] = &["li", "e", "la", "pi", "en", "se"];
+
+/// Grammar errors the linter also reports, worded the same way in both.
+pub const SE_NOT_FIRST: &str = "`se` must start the sentence";
+pub const E_WITHOUT_OBJECT: &str = "`e` must be followed by an object";
+pub const PI_WITHOUT_GROUP: &str = "`pi` must be followed by a modifier group";
+
+/// Words that open a prepositional phrase after a predicate.
+pub const PREPOSITIONS: &[&str] = &["lon", "tan", "tawa", "kepeken", "sama"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    /// The offending token, or the end of input.
+    pub span: Span,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Parser {
+    pub fn parse(&self, input: &str) -> Result<Sentence, ParseError> {
+        self.parse_tokens(&self.tokenize(input))
+    }
+
+    /// Builds a Kana sentence tree from tokens. Punctuation is skipped.
+    pub fn parse_tokens(&self, tokens: &[Token]) -> Result<Sentence, ParseError> {
+        let words: Vec<(Span, String)> = tokens
+            .iter()
+            .filter_map(|token| Some((token.span, token.word()?.to_lowercase())))
+            .collect();
+
+        let mut cursor = Cursor {
+            words: &words,
+            pos: 0,
+            end: tokens.last().map(|t| t.span.after()).unwrap_or_default(),
+        };
+        cursor.sentence()
+    }
+}
+
+struct Cursor<'a> {
+    words: &'a [(Span, String)],
+    pos: usize,
+    end: Span,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&str> {
+        self.words.get(self.pos + offset).map(|(_, w)| w.as_str())
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let word = self.words.get(self.pos).map(|(_, w)| w.clone());
+        self.pos += 1;
+        word
+    }
+
+    fn span(&self) -> Span {
+        self.words
+            .get(self.pos)
+            .map(|(span, _)| *span)
+            .unwrap_or(self.end)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span: self.span(),
+        }
+    }
+
+    fn sentence(&mut self) -> Result<Sentence, ParseError> {
+        let mut sentence = Sentence::default();
+
+        if self.peek().is_none() {
+            return Err(self.error("empty sentence"));
+        }
+        if self.peek() == Some("anu") {
+            sentence.alternative = true;
+            self.pos += 1;
+            if self.peek().is_none() {
+                return Err(self.error("`anu` must be followed by a phrase"));
+            }
+        }
+        if self.peek() == Some("yu") {
+            sentence.greeting = true;
+            self.pos += 1;
+            if self.peek().is_none() {
+                return Ok(sentence);
+            }
+        }
+        if self.peek() == Some("se") {
+            sentence.question = true;
+            self.pos += 1;
+        }
+
+        let la = self.words[self.pos..].iter().position(|(_, w)| w == "la");
+        if let Some(offset) = la {
+            if offset == 0 {
+                return Err(self.error("`la` must follow a context"));
+            }
+            let end = self.pos + offset;
+            sentence.context = Some(self.clause(end)?);
+            self.pos = end + 1;
+            if self.peek().is_none() {
+                return Err(self.error("`la` must be followed by a sentence"));
+            }
+        }
+
+        sentence.clause = self.clause(self.words.len())?;
+        Ok(sentence)
+    }
+
+    fn clause(&mut self, end: usize) -> Result<Clause, ParseError> {
+        let mut clause = Clause::default();
+        let has_li = self.words[self.pos..end].iter().any(|(_, w)| w == "li");
+
+        // `mi` and `sina` take their first predicate directly, without `li`
+        // (`mi moku li lape`). `mi mute li ...` is "we", though.
+        let bare_pronoun = matches!(self.peek(), Some("mi") | Some("sina"))
+            && !matches!(self.peek_at(1), Some("en" | "anu" | "li"))
+            && !(has_li && matches!(self.peek_at(1), Some("mute" | "tu")));
+        if bare_pronoun {
+            let head = self.next().unwrap_or_default();
+            clause.subjects.push(Phrase::new(head));
+            if self.pos < end && self.peek() != Some("e") {
+                let verb = self.phrase(end, false, true)?;
+                let predicate = self.predicate_tail(verb, end)?;
+                clause.predicates.push(predicate);
+            }
+        } else {
+            clause.subjects.push(self.phrase(end, false, false)?);
+            while self.pos < end && self.peek() == Some("en") {
+                self.pos += 1;
+                clause.subjects.push(self.phrase(end, false, false)?);
+            }
+        }
+
+        while self.pos < end {
+            match self.peek() {
+                Some("li") => {
+                    self.pos += 1;
+                    if self.pos >= end {
+                        return Err(self.error("`li` must be followed by a predicate"));
+                    }
+                    let verb = self.phrase(end, false, true)?;
+                    let predicate = self.predicate_tail(verb, end)?;
+                    clause.predicates.push(predicate);
+                }
+                Some("e") => return Err(self.error("`e` needs a predicate before it")),
+                Some("se") => return Err(self.error(SE_NOT_FIRST)),
+                Some(word) => {
+                    return Err(self.error(format!("unexpected `{}`", word)));
+                }
+                None => break,
+            }
+        }
+
+        Ok(clause)
+    }
+
+    fn predicate_tail(&mut self, verb: Phrase, end: usize) -> Result<Predicate, ParseError> {
+        let mut predicate = Predicate {
+            verb,
+            objects: Vec::new(),
+            prepositions: Vec::new(),
+        };
+
+        while self.pos < end {
+            match self.peek() {
+                Some("e") => {
+                    self.pos += 1;
+                    if self.pos >= end || self.peek().is_some_and(|w| MARKERS.contains(&w)) {
+                        return Err(self.error(E_WITHOUT_OBJECT));
+                    }
+                    predicate.objects.push(self.phrase(end, false, true)?);
+                }
+                Some(word) if self.starts_preposition(word, end) => {
+                    let preposition = self.next().unwrap_or_default();
+                    let object = self.phrase(end, false, true)?;
+                    predicate.prepositions.push(Prepositional {
+                        preposition,
+                        object,
+                    });
+                }
+                _ => break,
+            }
+        }
+
+        Ok(predicate)
+    }
+
+    fn starts_preposition(&self, word: &str, end: usize) -> bool {
+        PREPOSITIONS.contains(&word)
+            && self.pos + 1 < end
+            && self.peek_at(1).is_some_and(|w| !MARKERS.contains(&w))
+    }
+
+    /// Parses `head [modifier | pi group | ala]... [anu phrase]...`. Inside
+    /// a `pi` group (`nested`) another `pi` or an `anu` ends the group. In
+    /// predicates (`predicate_side`) a preposition with an object ends the
+    /// phrase.
+    fn phrase(
+        &mut self,
+        end: usize,
+        nested: bool,
+        predicate_side: bool,
+    ) -> Result<Phrase, ParseError> {
+        let head = match self.peek() {
+            Some(word) if self.pos < end && !MARKERS.contains(&word) && word != "ala" => {
+                self.next().unwrap_or_default()
+            }
+            Some(word) if self.pos < end => {
+                return Err(self.error(format!("expected a word, found `{}`", word)));
+            }
+            _ => return Err(self.error("expected a word")),
+        };
+        let mut phrase = Phrase::new(head);
+
+        while self.pos < end {
+            match self.peek() {
+                Some("ala") => {
+                    phrase.negated = true;
+                    self.pos += 1;
+                }
+                Some("pi" | "anu") if nested => break,
+                Some("anu") => {
+                    self.pos += 1;
+                    if self.pos >= end || self.peek().is_some_and(|w| MARKERS.contains(&w)) {
+                        return Err(self.error("`anu` must be followed by a phrase"));
+                    }
+                    let mut alternative = self.phrase(end, false, predicate_side)?;
+                    let more = std::mem::take(&mut alternative.alternatives);
+                    phrase.alternatives.push(alternative);
+                    phrase.alternatives.extend(more);
+                }
+                Some("pi") => {
+                    self.pos += 1;
+                    if self.pos >= end || self.peek().is_some_and(|w| MARKERS.contains(&w)) {
+                        return Err(self.error(PI_WITHOUT_GROUP));
+                    }
+                    let group = self.phrase(end, true, predicate_side)?;
+                    phrase.modifiers.push(Modifier::Pi(group));
+                }
+                Some(word) if MARKERS.contains(&word) => break,
+                Some(word) if predicate_side && self.starts_preposition(word, end) => break,
+                Some(_) => {
+                    let word = self.next().unwrap_or_default();
+                    phrase.modifiers.push(Modifier::Word(word));
+                }
+                None => break,
+            }
+        }
+
+        Ok(phrase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Sentence {
+        Parser::new().parse(input).unwrap()
+    }
+
+    fn parse_err(input: &str) -> ParseError {
+        Parser::new().parse(input).unwrap_err()
+    }
+
+    fn predicate(verb: Phrase, objects: Vec<Phrase>) -> Predicate {
+        Predicate {
+            verb,
+            objects,
+            prepositions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn bare_mi_takes_its_predicate_without_li() {
+        let sentence = parse("mi moku");
+        assert_eq!(sentence.clause.subjects, [Phrase::new("mi")]);
+        assert_eq!(
+            sentence.clause.predicates,
+            [predicate(Phrase::new("moku"), Vec::new())]
+        );
+    }
+
+    #[test]
+    fn multiple_li_are_separate_predicates() {
+        let sentence = parse("jan li moku li lape");
+        assert_eq!(sentence.clause.subjects, [Phrase::new("jan")]);
+        assert_eq!(
+            sentence.clause.predicates,
+            [
+                predicate(Phrase::new("moku"), Vec::new()),
+                predicate(Phrase::new("lape"), Vec::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn e_marks_each_object() {
+        let sentence = parse("jan li moku e moku e telo");
+        assert_eq!(
+            sentence.clause.predicates,
+            [predicate(
+                Phrase::new("moku"),
+                vec![Phrase::new("moku"), Phrase::new("telo")]
+            )]
+        );
+    }
+
+    #[test]
+    fn la_splits_off_the_context() {
+        let sentence = parse("sina moku la mi lape");
+        let context = sentence.context.as_ref().unwrap();
+        assert_eq!(context.subjects, [Phrase::new("sina")]);
+        assert_eq!(sentence.clause.subjects, [Phrase::new("mi")]);
+        assert_eq!(sentence.to_string(), "sina moku la mi lape");
+    }
+
+    #[test]
+    fn pi_groups_modifiers() {
+        let sentence = parse("tomo pi jan pona li suli");
+        let mut group = Phrase::new("jan");
+        group.modifiers.push(Modifier::Word("pona".to_string()));
+        let mut subject = Phrase::new("tomo");
+        subject.modifiers.push(Modifier::Pi(group));
+        assert_eq!(sentence.clause.subjects, [subject]);
+    }
+
+    #[test]
+    fn ala_negates_the_phrase() {
+        let sentence = parse("mi moku ala e telo");
+        let mut verb = Phrase::new("moku");
+        verb.negated = true;
+        assert_eq!(
+            sentence.clause.predicates,
+            [predicate(verb, vec![Phrase::new("telo")])]
+        );
+    }
+
+    #[test]
+    fn se_and_yu_open_the_sentence() {
+        let sentence = parse("yu se sina pona");
+        assert!(sentence.greeting);
+        assert!(sentence.question);
+        assert_eq!(sentence.to_string(), "yu se sina pona");
+    }
+
+    #[test]
+    fn anu_first_offers_an_alternative_sentence() {
+        let sentence = parse("anu sina lape");
+        assert!(sentence.alternative);
+        assert_eq!(sentence.clause.subjects, [Phrase::new("sina")]);
+        assert_eq!(sentence.to_string(), "anu sina lape");
+        assert_eq!(
+            parse_err("anu").message,
+            "`anu` must be followed by a phrase"
+        );
+    }
+
+    #[test]
+    fn se_after_the_start_is_an_error() {
+        let err = parse_err("jan se li moku");
+        assert_eq!(err.message, "`se` must start the sentence");
+        assert_eq!(err.span.range(), 4..6);
+    }
+
+    #[test]
+    fn dangling_e_points_at_the_end() {
+        let err = parse_err("jan li moku e");
+        assert_eq!(err.message, "`e` must be followed by an object");
+        assert_eq!(err.span.range(), 13..13);
+    }
+
+    #[test]
+    fn la_without_context_is_an_error() {
+        let err = parse_err("la mi moku");
+        assert_eq!(err.message, "`la` must follow a context");
+        assert_eq!((err.span.line, err.span.column), (1, 1));
+    }
+
+    #[test]
+    fn tokens_carry_byte_and_char_spans() {
+        let tokens = Parser::new().tokenize("żółw je.\nmi");
+        let spans: Vec<_> = tokens
+            .iter()
+            .map(|t| {
+                (
+                    t.span.range(),
+                    t.span.char_start,
+                    t.span.line,
+                    t.span.column,
+                )
+            })
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                (0..7, 0, 1, 1),
+                (8..10, 5, 1, 6),
+                (10..11, 7, 1, 8),
+                (12..14, 9, 2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn error_spans_count_lines_and_chars() {
+        let err = parse_err("jan li\nmoku e li");
+        assert_eq!(err.message, "`e` must be followed by an object");
+        assert_eq!(err.span.range(), 14..16);
+        assert_eq!((err.span.line, err.span.column), (2, 8));
+    }
+
+    fn english_words(input: &str) -> Vec<String> {
+        Parser::new()
+            .tokenize_english(input)
+            .iter()
+            .map(|t| match &t.kind {
+                TokenKind::Word(word) => word.clone(),
+                TokenKind::Punctuation(ch) => ch.to_string(),
+                TokenKind::Possessive => "'s".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn contractions_expand() {
+        assert_eq!(english_words("I'm here"), ["I", "am", "here"]);
+        assert_eq!(english_words("you’re"), ["you", "are"]);
+        assert_eq!(
+            english_words("can't won't don't"),
+            ["can", "not", "will", "not", "do", "not"]
+        );
+        assert_eq!(english_words("it's good"), ["it", "is", "good"]);
+        assert_eq!(english_words("we'll"), ["we", "will"]);
+    }
+
+    #[test]
+    fn possessive_s_is_its_own_token() {
+        assert_eq!(
+            english_words("the person's tool"),
+            ["the", "person", "'s", "tool"]
+        );
+        assert_eq!(
+            english_words("the friends' house"),
+            ["the", "friends", "'s", "house"]
+        );
+        assert_eq!(english_words("let's eat"), ["let's", "eat"]);
+    }
+
+    #[test]
+    fn expanded_tokens_split_the_span() {
+        let tokens = Parser::new().tokenize_english("I'm");
+        assert_eq!(tokens[0].span.range(), 0..1);
+        assert_eq!(tokens[1].span.range(), 1..3);
+        assert_eq!(tokens[1].span.column, 2);
+    }
+
+    #[test]
+    fn sentences_split_at_terminators() {
+        let parser = Parser::new();
+        let input = "mi moku. sina lape? jan li pona! ona li suli; mi lape";
+        let tokens = parser.tokenize(input);
+        let segments = parser.sentences(&tokens);
+        let found: Vec<_> = segments
+            .iter()
+            .map(|segment| (segment.text(input), segment.terminator))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("mi moku", Some('.')),
+                ("sina lape", Some('?')),
+                ("jan li pona", Some('!')),
+                ("ona li suli", Some(';')),
+                ("mi lape", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn sentences_without_words_are_dropped() {
+        let parser = Parser::new();
+        let tokens = parser.tokenize("mi moku... ?! sina lape");
+        let segments = parser.sentences(&tokens);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].terminator, Some('.'));
+        assert_eq!(segments[1].terminator, None);
+    }
+}
@@ -0,0 +1,167 @@
+//! "Did you mean" suggestions for unknown Kana words.
+//!
+//! Candidates are ranked by edit distance, both to the word as written and
+//! to its closest Kana spelling: Kana has no doubled letters and no b, d, g,
+//! r and so on, so "sinna" and "bona" are almost certainly `sina` and `pona`.
+
+use crate::dict::Dictionary;
+
+/// At most this many suggestions are returned.
+const LIMIT: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion<'a> {
+    pub kana: &'a str,
+    /// Edits needed to turn the unknown word into `kana`.
+    pub distance: usize,
+}
+
+/// Dictionary words closest to `word`. Only the nearest matches are kept,
+/// so a near-certain fix is not drowned out by weaker guesses.
+pub fn suggest<'a>(dict: &'a Dictionary, word: &str) -> Vec<Suggestion<'a>> {
+    let word = word.to_lowercase();
+    let respelled = respell(&word);
+    // One edit for short words, where two would match almost anything.
+    let max = if word.chars().count() <= 3 { 1 } else { 2 };
+
+    // A typo of one word is a typo of a single-word entry, not a compound.
+    let mut suggestions: Vec<Suggestion> = dict
+        .words()
+        .iter()
+        .filter(|entry| !entry.kana.contains(' '))
+        .map(|entry| Suggestion {
+            kana: &entry.kana,
+            distance: edit_distance(&word, &entry.kana).min(edit_distance(&respelled, &entry.kana)),
+        })
+        .filter(|s| s.distance <= max && s.kana != word)
+        .collect();
+
+    let first = word.chars().next();
+    suggestions.sort_by_key(|s| (s.distance, s.kana.chars().next() != first, s.kana));
+    suggestions.dedup_by_key(|s| s.kana);
+    if let Some(best) = suggestions.first().map(|s| s.distance) {
+        suggestions.retain(|s| s.distance == best);
+    }
+    suggestions.truncate(LIMIT);
+    suggestions
+}
+
+/// The closest spelling that follows Kana phonotactics: foreign letters
+/// become their nearest Kana sound and doubled letters are collapsed.
+fn respell(word: &str) -> String {
+    let mut result = String::new();
+    for c in word.chars() {
+        let kana = match c {
+            'b' | 'f' => 'p',
+            'd' => 't',
+            'g' | 'c' | 'q' => 'k',
+            'r' => 'l',
+            'v' => 'w',
+            'y' => 'j',
+            'z' | 'x' => 's',
+            'h' => continue,
+            other => other,
+        };
+        if !result.ends_with(kana) {
+            result.push(kana);
+        }
+    }
+    result
+}
+
+/// Levenshtein distance with adjacent transpositions ("mkou" → "moku" is one edit).
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// "did you mean `moku`?" or "did you mean `sina`, `sin` or `ni`?"
+pub fn did_you_mean(suggestions: &[Suggestion]) -> Option<String> {
+    let quoted: Vec<String> = suggestions
+        .iter()
+        .map(|s| format!("`{}`", s.kana))
+        .collect();
+    match quoted.as_slice() {
+        [] => None,
+        [only] => Some(format!("did you mean {}?", only)),
+        [rest @ .., last] => Some(format!("did you mean {} or {}?", rest.join(", "), last)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kana(dict: &Dictionary, word: &str) -> Vec<String> {
+        suggest(dict, word)
+            .iter()
+            .map(|s| s.kana.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn respelling_follows_kana_sounds() {
+        assert_eq!(respell("bona"), "pona");
+        assert_eq!(respell("sinna"), "sina");
+        assert_eq!(respell("hello"), "elo");
+    }
+
+    #[test]
+    fn transposition_is_one_edit() {
+        assert_eq!(edit_distance("mkou", "moku"), 1);
+        assert_eq!(edit_distance("moku", "moku"), 0);
+        assert_eq!(edit_distance("", "pona"), 4);
+    }
+
+    #[test]
+    fn nearest_words_only() {
+        let dict = Dictionary::new();
+        assert_eq!(kana(&dict, "mokuu"), ["moku"]);
+        assert_eq!(kana(&dict, "bona"), ["pona"]);
+        assert!(kana(&dict, "xyzzyq").is_empty());
+    }
+
+    #[test]
+    fn compounds_are_not_suggested_for_one_word() {
+        let dict = Dictionary::from_reader(
+            "tomo\tentity\thouse\ntomo tawa\tentity\tcar\nto\tentity\ttoe\n".as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(kana(&dict, "tomotawa"), Vec::<String>::new());
+        assert_eq!(kana(&dict, "tomu"), ["tomo"]);
+    }
+
+    #[test]
+    fn did_you_mean_lists_the_options() {
+        let one = [Suggestion {
+            kana: "moku",
+            distance: 1,
+        }];
+        assert_eq!(did_you_mean(&one).unwrap(), "did you mean `moku`?");
+        let three = ["e", "jo", "ko"].map(|kana| Suggestion { kana, distance: 1 });
+        assert_eq!(
+            did_you_mean(&three).unwrap(),
+            "did you mean `e`, `jo` or `ko`?"
+        );
+        assert_eq!(did_you_mean(&[]), None);
+    }
+}
@@ -1,260 +1,890 @@
-use crate::dict::Dictionary;
-use crate::parser::Parser;
+use crate::ast::{Clause, Modifier, Phrase, Predicate, Prepositional, Sentence};
+use crate::dict::{Dictionary, Lookup, WordType};
+use crate::lang::{Lang, Rules};
+use crate::parser::{PREPOSITIONS, Parser, Segment, Span, Token, TokenKind};
+use crate::suggest::{did_you_mean, suggest};
+use std::fmt;
+use std::ops::Range;
 
+#[derive(Clone)]
 pub struct Translator {
     dict: Dictionary,
     parser: Parser,
 }
 
+/// A translation together with anything the reader should know about it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translation {
+    pub text: String,
+    pub notes: Vec<Note>,
+}
+
+/// A remark about one word of the input, such as a thesaurus substitution
+/// or a suggestion for a misspelled Kana word.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    pub message: String,
+    pub span: Span,
+}
+
+/// Text carried from one natural language to another through Kana.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pivot {
+    /// The intermediate Kana, with notes about the source text.
+    pub kana: Translation,
+    /// The Kana in the target language, with notes about the Kana.
+    pub target: Translation,
+}
+
+impl fmt::Display for Note {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
 impl Translator {
     pub fn new() -> Self {
-        let dict = Dictionary::new();
+        Self::with_dictionary(Dictionary::new())
+    }
+
+    pub fn with_dictionary(dict: Dictionary) -> Self {
         let parser = Parser::new();
         Translator { dict, parser }
     }
 
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dict
+    }
+
+    /// The language translated to and from Kana: the dictionary's.
+    pub fn lang(&self) -> Lang {
+        self.dict.lang()
+    }
+
+    /// Translates text in the dictionary's language into Kana.
+    pub fn to_kana(&self, input: &str) -> String {
+        self.to_kana_annotated(input).text
+    }
+
+    /// Like `to_kana`, but also reports words that were replaced by a
+    /// related concept.
+    pub fn to_kana_annotated(&self, input: &str) -> Translation {
+        let tokens = self.parser.tokenize_in(self.lang(), input);
+        let mut notes = Vec::new();
+        let sentences: Vec<String> = self
+            .parser
+            .sentences(&tokens)
+            .iter()
+            .map(|segment| {
+                let mut kana = self.sentence_to_kana(input, segment, &mut notes);
+                if let Some(terminator) = segment.terminator {
+                    kana.push(terminator);
+                }
+                kana
+            })
+            .collect();
+
+        let text = if sentences.is_empty() {
+            input.to_string()
+        } else {
+            sentences.join(" ")
+        };
+        Translation { text, notes }
+    }
+
+    fn sentence_to_kana(&self, input: &str, segment: &Segment, notes: &mut Vec<Note>) -> String {
+        let rules = self.lang().rules();
+        let is_question = segment.terminator == Some('?');
+
+        let mut units = self.phrase_units(segment);
+
+        // Greetings ("hello", "good morning") open the sentence with `yu`
+        // and the rest is translated as usual.
+        let greetings = units
+            .iter()
+            .take_while(|unit| self.unit_kana(unit) == Some("yu"))
+            .count();
+        units.drain(..greetings);
+
+        let mut items = Vec::new();
+        for (i, unit) in units.iter().enumerate() {
+            let lower = unit.text.to_lowercase();
+            let next = units.get(i + 1).map(|unit| unit.text.to_lowercase());
+            let opens_question = i == 0 && is_question;
+
+            let kind = if unit.phrase.is_none()
+                && rules.is_auxiliary(&lower, next.as_deref(), opens_question)
+            {
+                continue;
+            } else if unit.phrase.is_none() && rules.conjunctions.contains(&lower.as_str()) {
+                ItemKind::And
+            } else if unit.phrase.is_none() && rules.disjunctions.contains(&lower.as_str()) {
+                ItemKind::Or
+            } else if rules.negations.contains(&lower.as_str()) {
+                ItemKind::Negation
+            } else if rules.articles.contains(&lower.as_str()) {
+                continue;
+            } else if rules.intensifiers.contains(&lower.as_str()) {
+                ItemKind::Intensifier
+            } else if unit.phrase.is_none()
+                && next.is_some()
+                && let Some(kana) = rules.possessive(&lower)
+                && (rules.pronoun(&lower).is_none() || self.is_noun(units.get(i + 1)))
+            {
+                ItemKind::Possessive(kana)
+            } else if unit.phrase.is_none() && rules.is_copula(&lower) {
+                ItemKind::Copula
+            } else if let (None, Some(kana)) = (&unit.phrase, rules.pronoun(&lower)) {
+                ItemKind::Pronoun(kana)
+            } else if let (None, true, Some(kana)) =
+                (&unit.phrase, lower.contains(' '), rules.preposition(&lower))
+            {
+                ItemKind::Preposition(kana)
+            } else {
+                let found = unit
+                    .phrase
+                    .clone()
+                    .or_else(|| self.lookup(&unit.text, unit.span, notes));
+                match (found, rules.preposition(&lower)) {
+                    (Some(found), _) if found.kana == "la" => ItemKind::Condition,
+                    (Some(found), None) => {
+                        ItemKind::Word(found.kana.to_string(), Some(found.wtype))
+                    }
+                    // "como" is "like", but also "I eat".
+                    (Some(found), Some(_)) if found.wtype != WordType::Particle => {
+                        ItemKind::Word(found.kana.to_string(), Some(found.wtype))
+                    }
+                    (_, Some(kana)) => ItemKind::Preposition(kana),
+                    (None, None) => ItemKind::Word(format!("[{}]", unit.text), None),
+                }
+            };
+            items.push(Item {
+                text: lower,
+                kind,
+                comma: unit.comma,
+                possessive: unit.possessive,
+            });
+        }
+
+        let build = |items: &[Item]| {
+            let mut builder = ClauseBuilder {
+                items,
+                pos: 0,
+                rules,
+                copula: false,
+            };
+            // Each clause, and whether it is an alternative to the one before.
+            let mut clauses = vec![(false, builder.clause())];
+            // "I eat and you sleep": a new subject starts another clause, as
+            // does "or" between predicates.
+            while let Some(kind @ (ItemKind::And | ItemKind::Or)) = builder.peek() {
+                let or = matches!(kind, ItemKind::Or);
+                builder.pos += 1;
+                builder.copula = false;
+                let clause = if or && !builder.starts_clause() {
+                    // "I eat food or drink water" → `mi moku e moku; anu mi telo e telo`
+                    let mut clause = Clause {
+                        subjects: clauses[clauses.len() - 1].1.subjects.clone(),
+                        predicates: Vec::new(),
+                    };
+                    builder.predicates(&mut clause);
+                    clause
+                } else {
+                    builder.clause()
+                };
+                clauses.push((or, clause));
+            }
+            clauses
+                .retain(|(_, clause)| !clause.subjects.is_empty() || !clause.predicates.is_empty());
+            clauses
+        };
+        let (context, clause) = split_context(&items);
+        // "heute esse ich": the verb went before the subject.
+        if rules.verb_second
+            && context.is_some()
+            && let [verb, subject, ..] = &items[clause.clone()]
+            && matches!(verb.kind, ItemKind::Word(..) | ItemKind::Copula)
+            && matches!(subject.kind, ItemKind::Pronoun(_))
+        {
+            items.swap(clause.start, clause.start + 1);
+        }
+        // Only one clause can be the `la` context; any before it become
+        // sentences of their own.
+        let mut earlier = context.map_or_else(Vec::new, |context| build(&items[context]));
+        let context = earlier.pop().map(|(_, context)| context);
+        let mut clauses = build(&items[clause]).into_iter();
+        let clause = clauses.next().map(|(_, clause)| clause);
+        let has_clause = clause.is_some();
+        let sentence = Sentence {
+            alternative: false,
+            greeting: greetings > 0,
+            question: is_question && has_clause,
+            context: context.filter(|context| !context.subjects.is_empty()),
+            clause: clause.unwrap_or_default(),
+        };
+
+        if !sentence.greeting && !has_clause {
+            return segment.text(input).to_string();
+        }
+
+        // Kana has no word joining whole clauses, so each one is its own
+        // sentence: "I eat and you sleep" → `mi moku; sina lape`, and "I eat
+        // or you sleep" → `mi moku; anu sina lape`.
+        let alternative = |(or, clause): (bool, Clause)| {
+            Sentence {
+                alternative: or,
+                clause,
+                ..Sentence::default()
+            }
+            .to_string()
+        };
+        let mut parts: Vec<String> = earlier.into_iter().map(alternative).collect();
+        parts.push(sentence.to_string());
+        parts.extend(clauses.map(alternative));
+        parts.join("; ")
+    }
+
+    /// The words of a segment, with multi-word dictionary phrases ("thank
+    /// you", "a lot") matched longest first and kept together, as are
+    /// prepositions of several words the dictionary doesn't list.
+    fn phrase_units(&self, segment: &Segment) -> Vec<Unit<'_>> {
+        let tokens = segment.tokens;
+        // Each word with the index of its token.
+        let words: Vec<(&str, Span, usize)> = tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| Some((t.word()?, t.span, i)))
+            .collect();
+        let followed_by = |token: usize, kind: TokenKind| {
+            tokens.get(token + 1).is_some_and(|next| next.kind == kind)
+        };
+        let rules = self.lang().rules();
+        let mut units = Vec::new();
+
+        let mut i = 0;
+        while i < words.len() {
+            let rest: Vec<&str> = words[i..].iter().map(|(w, _, _)| *w).collect();
+            let (n, phrase) = match self.dict.lookup_phrase(&rest) {
+                Some((n, found)) => (n, Some(found)),
+                None => (rules.long_preposition(&rest).unwrap_or(1), None),
+            };
+            let first = words[i].1;
+            let (_, last, token) = words[i + n - 1];
+            units.push(Unit {
+                text: rest[..n].join(" "),
+                span: Span {
+                    end: last.end,
+                    char_end: last.char_end,
+                    ..first
+                },
+                phrase,
+                comma: followed_by(token, TokenKind::Punctuation(',')),
+                possessive: followed_by(token, TokenKind::Possessive),
+            });
+            i += n;
+        }
+
+        units
+    }
+
+    /// Whether a unit can only be a noun or adjective, so a word before it
+    /// that is also a pronoun is possessive: "I see her house", but "I see
+    /// her" and "ihr esst".
+    fn is_noun(&self, unit: Option<&Unit>) -> bool {
+        let Some(found) = unit.and_then(|unit| self.dict.lookup(&unit.text)) else {
+            return false;
+        };
+        !TIMES.contains(&found.kana)
+            && self.dict.entry(found.kana).is_some_and(|word| {
+                word.senses
+                    .iter()
+                    .all(|sense| sense.wtype != WordType::Action)
+            })
+    }
+
+    fn unit_kana<'a>(&'a self, unit: &'a Unit) -> Option<&'a str> {
+        match &unit.phrase {
+            Some(found) => Some(found.kana),
+            None => self.dict.translate_gloss(&unit.text),
+        }
+    }
+
+    /// Dictionary lookup that notes when the thesaurus stood in for a word.
+    fn lookup(&self, word: &str, span: Span, notes: &mut Vec<Note>) -> Option<Lookup<'_>> {
+        let found = self.dict.lookup(word)?;
+        if let Some(relation) = found.relation {
+            notes.push(Note {
+                message: format!(
+                    "`{}` is not in the dictionary; used `{}` ({})",
+                    word, found.kana, relation
+                ),
+                span,
+            });
+        }
+        Some(found)
+    }
+
+    /// Translates Kana into the dictionary's language.
+    pub fn from_kana(&self, input: &str) -> String {
+        self.from_kana_annotated(input).text
+    }
+
+    /// Like `from_kana`, but also suggests dictionary words for the unknown
+    /// words it left as `[word]`.
+    pub fn from_kana_annotated(&self, input: &str) -> Translation {
+        let notes = self
+            .parser
+            .tokenize(input)
+            .iter()
+            .filter_map(|token| {
+                let word = token.word()?.to_lowercase();
+                if word.starts_with('[') || self.dict.entry(&word).is_some() {
+                    return None;
+                }
+                let message = match did_you_mean(&suggest(&self.dict, &word)) {
+                    Some(help) => format!("unknown word `{}`; {}", word, help),
+                    None => format!("unknown word `{}`", word),
+                };
+                Some(Note {
+                    message,
+                    span: token.span,
+                })
+            })
+            .collect();
+
+        Translation {
+            text: self.translate_kana(input),
+            notes,
+        }
+    }
+
+    #[deprecated(note = "use `to_kana`, which translates from the dictionary's language")]
     pub fn english_to_kana(&self, input: &str) -> String {
+        self.to_kana(input)
+    }
+
+    #[deprecated(note = "use `from_kana`, which translates into the dictionary's language")]
+    pub fn kana_to_english(&self, input: &str) -> String {
+        self.from_kana(input)
+    }
+
+    fn translate_kana(&self, input: &str) -> String {
         let tokens = self.parser.tokenize(input);
-        let mut result = Vec::new();
-        let mut has_subject = false;
-        let mut has_verb = false;
-        let mut has_object = false;
-        let mut is_question = false;
+        let sentences: Vec<String> = self
+            .parser
+            .sentences(&tokens)
+            .iter()
+            .map(|segment| self.sentence_from_kana(input, segment))
+            .collect();
 
-        if input.trim().ends_with('?') {
-            is_question = true;
+        if sentences.is_empty() {
+            return input.to_string();
         }
 
-        if input.to_lowercase().starts_with("hello")
-            || input.to_lowercase().starts_with("hi ")
-            || input.to_lowercase().starts_with("hey")
-        {
-            result.push("yu".to_string());
-            return result.join(" ");
+        sentences.join(" ")
+    }
+
+    fn sentence_from_kana(&self, input: &str, segment: &Segment) -> String {
+        let tokens = self.merge_compounds(segment.tokens);
+        let segment = &Segment {
+            tokens: &tokens,
+            terminator: segment.terminator,
+        };
+        let sentence = match self.parser.parse_tokens(segment.tokens) {
+            Ok(sentence) => sentence,
+            Err(_) => return self.gloss_tokens(input, segment),
+        };
+
+        let mut output = String::new();
+        if sentence.alternative {
+            output.push_str(self.lang().rules().or);
+            output.push(' ');
+        }
+        // "sina moku la" → "if you eat, "; "tenpo ni la" → "today, "
+        if let Some(context) = &sentence.context {
+            if !context.predicates.is_empty() {
+                output.push_str(self.lang().rules().condition);
+                output.push(' ');
+            }
+            output.push_str(&self.clause_from_kana(context));
+            output.push_str(", ");
         }
+        output.push_str(&self.clause_from_kana(&sentence.clause));
 
-        let mut pending_modifiers: Vec<String> = Vec::new();
+        if sentence.greeting {
+            let mut greeting = self.gloss("yu");
+            if output.is_empty() {
+                greeting.extend(segment.terminator);
+                return greeting;
+            }
+            output = format!("{}, {}", greeting, output);
+        }
 
-        for token in &tokens {
-            if let crate::parser::Token::Word(word) = token {
-                let lower = word.to_lowercase();
+        if output.is_empty() {
+            return segment.text(input).to_string();
+        }
 
-                if lower == "and" {
-                    continue;
-                } else if lower == "not" || lower == "no" || lower == "don't" {
-                    pending_modifiers.push("ala".to_string());
-                } else if lower == "the" || lower == "a" || lower == "an" {
-                    continue;
-                } else if lower == "very" || lower == "really" {
-                    pending_modifiers.push("mute".to_string());
-                } else if !has_subject {
-                    let kana: String = if lower == "i"
-                        || lower == "me"
-                        || lower == "we"
-                        || lower == "us"
-                    {
-                        "mi".to_string()
-                    } else if lower == "you" {
-                        "sina".to_string()
-                    } else if lower == "he" || lower == "she" || lower == "it" || lower == "they" {
-                        "ona".to_string()
-                    } else if let Some(k) = self.dict.translate_en_word(&lower) {
-                        k.to_string()
-                    } else {
-                        format!("[{}]", word)
-                    };
-                    result.push(kana);
-                    has_subject = true;
-                } else if !has_verb {
-                    if !result.contains(&"li".to_string())
-                        && !result
-                            .last()
-                            .map(|w| w == "sina" || w == "mi")
-                            .unwrap_or(false)
-                    {
-                        result.push("li".to_string());
-                    }
-                    if let Some(kana) = self.dict.translate_en_word(&lower) {
-                        result.push(kana.to_string());
-                    } else {
-                        result.push(format!("[{}]", word));
-                    }
-                    has_verb = true;
-                } else {
-                    let (kana, is_pronoun) = if lower == "i"
-                        || lower == "me"
-                        || lower == "we"
-                        || lower == "us"
-                    {
-                        ("mi", true)
-                    } else if lower == "you" {
-                        ("sina", true)
-                    } else if lower == "he" || lower == "she" || lower == "it" || lower == "they" {
-                        ("ona", true)
-                    } else if let Some(k) = self.dict.translate_en_word(&lower) {
-                        (k, false)
-                    } else {
-                        (lower.as_str(), false)
-                    };
+        output.push(sentence_end(sentence.question, segment.terminator));
+        output
+    }
 
-                    let is_entity = is_pronoun
-                        || matches!(
-                            self.dict.get_word_type(kana),
-                            Some(crate::dict::WordType::Entity)
-                                | Some(crate::dict::WordType::Number)
-                        );
+    /// Joins dictionary compounds ("tomo tawa") into single words, longest
+    /// first, so they are translated whole rather than word by word, inside
+    /// `pi` groups too: "tomo pi jan pona mi" is "house of my friend".
+    fn merge_compounds(&self, tokens: &[Token]) -> Vec<Token> {
+        let mut merged = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            let words: Vec<String> = tokens[i..]
+                .iter()
+                .take(self.dict.longest_compound())
+                .map_while(|t| Some(t.word()?.to_lowercase()))
+                .collect();
+            let words: Vec<&str> = words.iter().map(String::as_str).collect();
+            match self.dict.lookup_compound(&words) {
+                Some((n, entry)) => {
+                    let last = tokens[i + n - 1].span;
+                    merged.push(Token {
+                        kind: TokenKind::Word(entry.kana.to_string()),
+                        span: Span {
+                            end: last.end,
+                            char_end: last.char_end,
+                            ..tokens[i].span
+                        },
+                    });
+                    i += n;
+                }
+                _ => {
+                    merged.push(tokens[i].clone());
+                    i += 1;
+                }
+            }
+        }
+        merged
+    }
 
-                    if is_entity && !has_object {
-                        result.push("e".to_string());
-                        has_object = true;
-                    }
+    fn clause_from_kana(&self, clause: &Clause) -> String {
+        let rules = self.lang().rules();
+        let and = format!(" {} ", rules.and);
+        let subjects: Vec<String> = clause
+            .subjects
+            .iter()
+            .map(|subject| self.argument_from_kana(subject, Rules::subject_pronoun))
+            .collect();
 
-                    if kana == lower
-                        && !is_pronoun
-                        && !self.dict.translate_en_word(&lower).is_some()
-                    {
-                        result.push(format!("[{}]", word));
+        // Who "to be" and the verbs agree with: a pronoun, "" for any other
+        // single subject, or none, which leaves verbs in the infinitive.
+        // Several subjects are "we" if one is `mi` ("tú y yo comemos"),
+        // else "you" if one is `sina`, else "they".
+        let pronouns: Vec<Option<String>> = clause.subjects.iter().map(pronoun_kana).collect();
+        let person = match pronouns.as_slice() {
+            [] => None,
+            [pronoun] => Some(pronoun.clone().unwrap_or_default()),
+            _ => {
+                let includes = |person: &str| {
+                    pronouns
+                        .iter()
+                        .flatten()
+                        .any(|p| p.split(' ').next() == Some(person))
+                };
+                Some(
+                    if includes("mi") {
+                        "mi mute"
+                    } else if includes("sina") {
+                        "sina mute"
                     } else {
-                        result.push(kana.to_string());
+                        "ona"
                     }
-                }
+                    .to_string(),
+                )
             }
+        };
+        let be = rules.be(Some(person.as_deref().unwrap_or("ona")));
+        // "to be" isn't repeated: "I am good and strong".
+        let mut after_be = false;
+        let predicates: Vec<String> = clause
+            .predicates
+            .iter()
+            .map(|predicate| {
+                let takes_be = predicate.objects.is_empty()
+                    && self.complement_from_kana(&predicate.verb).is_some();
+                let repeated = after_be && takes_be && !predicate.verb.negated;
+                after_be = takes_be;
+                self.predicate_from_kana(
+                    predicate,
+                    if repeated { "" } else { be },
+                    person.as_deref(),
+                )
+            })
+            .collect();
+
+        let mut parts = Vec::new();
+        if !subjects.is_empty() {
+            parts.push(subjects.join(&and));
+        }
+        if !predicates.is_empty() {
+            parts.push(predicates.join(&and));
         }
+        parts.join(" ")
+    }
+
+    /// `be` is "to be" for the clause's subject, used when the predicate is
+    /// an adjective or noun ("sina pona" → "you are good"). A verb is
+    /// conjugated for `person`, as in `Rules::conjugate`.
+    fn predicate_from_kana(&self, predicate: &Predicate, be: &str, person: Option<&str>) -> String {
+        let rules = self.lang().rules();
+        let mut parts = Vec::new();
+
+        let complement = if predicate.objects.is_empty() {
+            self.complement_from_kana(&predicate.verb)
+        } else {
+            None
+        };
+        if let Some(complement) = complement {
+            let negated = predicate.verb.negated;
+            if negated && !rules.not_after_be {
+                parts.push(rules.negations[0].to_string());
+            }
+            parts.push(be.to_string());
+            if negated && rules.not_after_be {
+                parts.push(rules.negations[0].to_string());
+            }
+            parts.push(complement);
+            parts.extend(self.prepositions_from_kana(predicate));
+            parts.retain(|p| !p.is_empty());
+            return parts.join(" ");
+        }
+
+        // A verb with objects is read as an action; otherwise the word's
+        // default sense decides ("sina moku" is "you eat", not "you food").
+        let prefer: &[WordType] = if predicate.objects.is_empty() {
+            &[]
+        } else {
+            &[WordType::Action]
+        };
+        // "mi tawa tomo": going somewhere is "go to" the place, which is
+        // what follows `tawa` rather than a modifier of it.
+        let (verb, place) = match predicate.verb.modifiers.split_first() {
+            Some((Modifier::Word(head), rest))
+                if predicate.verb.head == "tawa" && predicate.objects.is_empty() =>
+            {
+                let mut place = Phrase::new(head.clone());
+                place.modifiers = rest.to_vec();
+                place.alternatives = predicate.verb.alternatives.clone();
+                (Phrase::new("tawa"), Some(place))
+            }
+            _ => (predicate.verb.clone(), None),
+        };
+        let verb = match person {
+            Some(person) => self.verb_from_kana(&verb, prefer, person),
+            None => self.phrase_from_kana(&verb, prefer),
+        };
+        let negated = predicate.verb.negated;
 
-        for modifier in pending_modifiers {
-            result.push(modifier);
+        let mut preposed = Vec::new();
+        let mut objects = Vec::new();
+        for object in &predicate.objects {
+            let text = self.argument_from_kana(object, Rules::object_pronoun);
+            let pronoun = pronoun_kana(object).is_some_and(|kana| {
+                rules.object_pronoun(&kana).is_some() && object.alternatives.is_empty()
+            });
+            if pronoun && rules.object_pronouns_first {
+                preposed.push(text);
+            } else {
+                objects.push(text);
+            }
         }
 
-        if is_question {
-            result.insert(0, "se".to_string());
+        if negated && !rules.negation_last {
+            parts.push(rules.not.to_string());
+        }
+        parts.extend(preposed);
+        parts.push(verb);
+        if !objects.is_empty() {
+            parts.push(objects.join(&format!(" {} ", rules.and)));
+        }
+        if negated && rules.negation_last {
+            parts.push(rules.not.to_string());
         }
 
-        if result.is_empty() {
-            return input.to_string();
+        if let Some(place) = place {
+            parts.push(self.preposition_from_kana("tawa"));
+            parts.push(self.noun_phrase_from_kana(&place, NOUN));
         }
+        parts.extend(self.prepositions_from_kana(predicate));
 
-        result.join(" ")
+        parts.retain(|p| !p.is_empty());
+        parts.join(" ")
     }
 
-    pub fn kana_to_english(&self, input: &str) -> String {
-        let tokens = self.parser.tokenize(input);
-        let mut result = Vec::new();
-        let mut stage = 0;
-        let mut is_question = false;
-        let mut is_negated = false;
-        let mut is_greeting = false;
+    /// What follows "to be" when a predicate has no verb: an adjective, a
+    /// noun, or a preposition with its object ("mi lon tomo" → "I am in
+    /// house"). Negation is left to the caller.
+    fn complement_from_kana(&self, verb: &Phrase) -> Option<String> {
+        let primary = self.dict.entry(&verb.head)?.primary().wtype;
+        if PREPOSITIONS.contains(&verb.head.as_str())
+            && primary != WordType::Action
+            && let Some((Modifier::Word(head), rest)) = verb.modifiers.split_first()
+        {
+            let mut object = Phrase::new(head.clone());
+            object.modifiers = rest.to_vec();
+            return Some(format!(
+                "{} {}",
+                self.preposition_from_kana(&verb.head),
+                self.noun_phrase_from_kana(&object, NOUN)
+            ));
+        }
+        let complement = Phrase {
+            negated: false,
+            ..verb.clone()
+        };
+        match primary {
+            WordType::Quality | WordType::Number => Some(self.or_from_kana(
+                &complement,
+                self.adjective_from_kana(&complement),
+                |alternative| self.adjective_from_kana(alternative),
+            )),
+            WordType::Entity => Some(self.noun_phrase_from_kana(&complement, NOUN)),
+            WordType::Action | WordType::Particle => None,
+        }
+    }
 
-        let lower_input = input.to_lowercase().trim().to_string();
-        if lower_input == "yu" || lower_input.starts_with("yu ") || lower_input == "y" {
-            is_greeting = true;
+    fn prepositions_from_kana(&self, predicate: &Predicate) -> Vec<String> {
+        let mut parts = Vec::new();
+        for prep in &predicate.prepositions {
+            parts.push(self.preposition_from_kana(&prep.preposition));
+            parts.push(self.noun_phrase_from_kana(&prep.object, NOUN));
         }
+        parts
+    }
 
-        for token in &tokens {
-            if let crate::parser::Token::Word(word) = token {
-                let lower = word.to_lowercase();
+    fn preposition_from_kana(&self, kana: &str) -> String {
+        match self.lang().rules().preposition_for(kana) {
+            Some(word) => word.to_string(),
+            None => self.gloss_as(kana, &[WordType::Particle]),
+        }
+    }
 
-                if lower == "yu" {
-                    result.push("hello".to_string());
-                    continue;
+    /// Like `phrase_from_kana`, but adjectives go before the head in
+    /// languages that put them first ("tomo pi suli mute" → "very big house").
+    fn noun_phrase_from_kana(&self, phrase: &Phrase, prefer: &[WordType]) -> String {
+        let rules = self.lang().rules();
+        // "tomo mi" → "my house"
+        let mut before: Vec<String> = phrase
+            .modifiers
+            .iter()
+            .find_map(|modifier| self.possessive_from_kana(modifier))
+            .map(String::from)
+            .into_iter()
+            .collect();
+        let mut words = vec![self.gloss_as(&phrase.head, prefer)];
+        for modifier in &phrase.modifiers {
+            if self.possessive_from_kana(modifier).is_some() {
+                continue;
+            }
+            let adjective = match modifier {
+                Modifier::Word(word) => self.gloss_as(word, MODIFIER),
+                Modifier::Pi(group) if self.is_adjective(&group.head) => {
+                    self.adjective_from_kana(group)
                 }
-
-                if lower == "se" {
-                    is_question = true;
+                // "tomo pi jan suli" → "house of big people"
+                Modifier::Pi(group) => {
+                    words.push(rules.of.to_string());
+                    words.push(self.noun_phrase_from_kana(group, NOUN));
                     continue;
                 }
+            };
+            if rules.adjectives_first {
+                before.push(adjective);
+            } else {
+                words.push(adjective);
+            }
+        }
+        before.extend(words);
+        before.retain(|w| !w.is_empty());
 
-                if lower == "li" {
-                    stage = 1;
-                    continue;
-                }
+        let mut words = before.join(" ");
+        if phrase.negated {
+            words = format!("{} {}", rules.no, words);
+        }
+        self.or_from_kana(phrase, words, |alternative| {
+            self.noun_phrase_from_kana(alternative, prefer)
+        })
+    }
 
-                if lower == "e" {
-                    stage = 2;
-                    continue;
-                }
+    /// A subject or object, with a bare pronoun in the form `pronoun` gives
+    /// ("sina anu mi" → "you or I").
+    fn argument_from_kana(
+        &self,
+        phrase: &Phrase,
+        pronoun: fn(&Rules, &str) -> Option<&'static str>,
+    ) -> String {
+        let kana = pronoun_kana(phrase);
+        let text = match kana.and_then(|kana| pronoun(self.lang().rules(), &kana)) {
+            Some(pronoun) => pronoun.to_string(),
+            None => {
+                let alone = Phrase {
+                    alternatives: Vec::new(),
+                    ..phrase.clone()
+                };
+                self.noun_phrase_from_kana(&alone, NOUN)
+            }
+        };
+        self.or_from_kana(phrase, text, |alternative| {
+            self.argument_from_kana(alternative, pronoun)
+        })
+    }
 
-                if lower == "pi" {
-                    continue;
-                }
+    /// The possessive determiner for a pronoun modifier: `mi` → "my",
+    /// `pi mi mute` → "our".
+    fn possessive_from_kana(&self, modifier: &Modifier) -> Option<&'static str> {
+        let rules = self.lang().rules();
+        match modifier {
+            Modifier::Word(word) => rules.possessive_for(word),
+            Modifier::Pi(group) => rules.possessive_for(&group.to_string()),
+        }
+    }
 
-                if lower == "ala" {
-                    is_negated = true;
-                    continue;
-                }
+    /// Adds the phrases offered in place of `phrase` to its translation:
+    /// "moku anu telo" → "food or water".
+    fn or_from_kana(
+        &self,
+        phrase: &Phrase,
+        text: String,
+        translate: impl Fn(&Phrase) -> String,
+    ) -> String {
+        let mut parts = vec![text];
+        parts.extend(phrase.alternatives.iter().map(translate));
+        parts.join(&format!(" {} ", self.lang().rules().or))
+    }
 
-                if lower == "en" {
-                    result.push("and".to_string());
-                    continue;
+    /// Whether a word is a quality or number by default.
+    fn is_adjective(&self, kana: &str) -> bool {
+        self.dict
+            .entry(kana)
+            .is_some_and(|word| MODIFIER.contains(&word.primary().wtype))
+    }
+
+    /// A `pi` group of an adjective and its intensifiers, in the order the
+    /// language has them: "suli mute" → "very big".
+    fn adjective_from_kana(&self, group: &Phrase) -> String {
+        let rules = self.lang().rules();
+        let mut words = Vec::new();
+        if group.negated {
+            words.push(rules.negations[0].to_string());
+        }
+        let mut after = Vec::new();
+        for modifier in &group.modifiers {
+            match modifier {
+                Modifier::Word(word) if word == "mute" => {
+                    words.push(rules.intensifiers[0].to_string())
                 }
+                Modifier::Word(word) => after.push(self.gloss_as(word, MODIFIER)),
+                Modifier::Pi(inner) => after.push(self.phrase_from_kana(inner, MODIFIER)),
+            }
+        }
+        words.push(self.gloss_as(&group.head, MODIFIER));
+        words.extend(after);
+        words.retain(|w| !w.is_empty());
+        words.join(" ")
+    }
 
-                if let Some(meanings) = self.dict.translate_kana_word(&lower) {
-                    let meaning = if !meanings.is_empty() {
-                        let m = meanings[0];
-                        if m.starts_with('[') {
-                            ""
-                        } else {
-                            m
-                        }
-                    } else {
-                        ""
-                    };
+    /// Glosses the head with the first sense of `prefer` it has, and
+    /// modifiers as qualities, in Kana order.
+    fn phrase_from_kana(&self, phrase: &Phrase, prefer: &[WordType]) -> String {
+        let head = self.gloss_as(&phrase.head, prefer);
+        self.or_from_kana(phrase, self.with_modifiers(phrase, head), |alternative| {
+            self.phrase_from_kana(alternative, prefer)
+        })
+    }
+
+    /// Like `phrase_from_kana`, with the head and each alternative's head
+    /// conjugated for `person` ("mi moku anu lape" → "jem albo śpię").
+    fn verb_from_kana(&self, phrase: &Phrase, prefer: &[WordType], person: &str) -> String {
+        let rules = self.lang().rules();
+        let head = rules.conjugate(&self.gloss_as(&phrase.head, prefer), Some(person));
+        self.or_from_kana(phrase, self.with_modifiers(phrase, head), |alternative| {
+            self.verb_from_kana(alternative, prefer, person)
+        })
+    }
 
-                    if !meaning.is_empty() {
-                        if stage == 0 {
-                            if let Some(trans) = self.get_english_pronoun(&lower) {
-                                result.push(trans.to_string());
-                            } else {
-                                result.push(meaning.to_string());
-                            }
-                        } else if stage == 1 {
-                            if is_negated {
-                                result.push(format!("do not {}", meaning));
-                                is_negated = false;
-                            } else {
-                                result.push(meaning.to_string());
-                            }
-                        } else {
-                            result.push(meaning.to_string());
+    fn with_modifiers(&self, phrase: &Phrase, head: String) -> String {
+        let mut words = vec![head];
+        for modifier in &phrase.modifiers {
+            match modifier {
+                Modifier::Word(word) => words.push(self.gloss_as(word, MODIFIER)),
+                Modifier::Pi(group) => words.push(self.phrase_from_kana(group, MODIFIER)),
+            }
+        }
+        words.retain(|w| !w.is_empty());
+        words.join(" ")
+    }
+
+    fn gloss(&self, word: &str) -> String {
+        self.gloss_as(word, &[])
+    }
+
+    fn gloss_as(&self, word: &str, prefer: &[WordType]) -> String {
+        match self.dict.entry(word) {
+            Some(entry) => entry
+                .sense_for(prefer)
+                .gloss()
+                .unwrap_or_default()
+                .to_string(),
+            // Already marked as untranslatable.
+            None if word.starts_with('[') => word.to_string(),
+            None => format!("[{}]", word),
+        }
+    }
+
+    /// Word-by-word gloss used when the input does not parse as a sentence.
+    fn gloss_tokens(&self, input: &str, segment: &Segment) -> String {
+        let mut result = Vec::new();
+        for token in segment.tokens {
+            if let Some(word) = token.word() {
+                let lower = word.to_lowercase();
+                let rules = self.lang().rules();
+                match lower.as_str() {
+                    "en" => result.push(rules.and.to_string()),
+                    "anu" => result.push(rules.or.to_string()),
+                    // The plain negation word ("not" rather than "do not").
+                    "ala" => result.push(rules.negations[0].to_string()),
+                    _ => {
+                        let meaning = self.gloss(&lower);
+                        if !meaning.is_empty() {
+                            result.push(meaning);
                         }
                     }
-                } else {
-                    result.push(format!("[{}]", word));
                 }
             }
         }
 
-        if is_greeting {
-            return "hello".to_string();
-        }
-
         if result.is_empty() {
-            return input.to_string();
+            return segment.text(input).to_string();
         }
 
         let mut output = result.join(" ");
-
-        if is_question {
-            output = format!("{}?", output);
-        } else {
-            output = format!("{}.", output);
-        }
-
+        output.push(sentence_end(false, segment.terminator));
         output
     }
 
-    fn get_english_pronoun(&self, kana: &str) -> Option<&'static str> {
-        match kana {
-            "mi" => Some("I"),
-            "sina" => Some("you"),
-            "ona" => Some("they"),
-            _ => None,
-        }
+    /// Translates text in this translator's language into `target`'s, by
+    /// way of Kana.
+    pub fn pivot(&self, target: &Translator, input: &str) -> Pivot {
+        let kana = self.to_kana_annotated(input);
+        let target = target.from_kana_annotated(&kana.text);
+        Pivot { kana, target }
     }
 
     pub fn translate(&self, input: &str, direction: &str) -> String {
+        self.translate_annotated(input, direction).text
+    }
+
+    /// Like `translate`, with notes about the translation.
+    pub fn translate_annotated(&self, input: &str, direction: &str) -> Translation {
         match direction {
-            "to" | "en2k" | "en-kana" => self.english_to_kana(input),
-            "from" | "k2en" | "kana-en" => self.kana_to_english(input),
+            "to" | "en2k" | "en-kana" => self.to_kana_annotated(input),
+            "from" | "k2en" | "kana-en" => self.from_kana_annotated(input),
             _ => {
                 let lower = input.to_lowercase();
                 let kana_words = [
@@ -263,19 +893,794 @@ impl Translator {
                 let is_kana = kana_words.iter().any(|w| lower.contains(w));
 
                 if is_kana {
-                    self.kana_to_english(input)
+                    self.from_kana_annotated(input)
                 } else {
-                    self.english_to_kana(input)
+                    self.to_kana_annotated(input)
                 }
             }
         }
     }
 }
 
-impl Clone for Translator {
-    fn clone(&self) -> Self {
-        let dict = Dictionary::new();
-        let parser = Parser::new();
-        Translator { dict, parser }
+impl Default for Translator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A word of input, or a phrase matched as a whole.
+struct Unit<'a> {
+    text: String,
+    span: Span,
+    phrase: Option<Lookup<'a>>,
+    /// A comma follows, which may end a context ("if you eat, ...").
+    comma: bool,
+    /// `'s` follows: the unit owns the noun after it.
+    possessive: bool,
+}
+
+/// A unit of input, classified for building a Kana sentence.
+struct Item {
+    /// The input, lowercased.
+    text: String,
+    kind: ItemKind,
+    /// A comma follows.
+    comma: bool,
+    /// `'s` follows.
+    possessive: bool,
+}
+
+enum ItemKind {
+    /// `ala` for the word after it, or for the verb if nothing follows.
+    Negation,
+    /// `mute` for the word after it, or for the verb if nothing follows.
+    Intensifier,
+    /// A form of "to be".
+    Copula,
+    /// A preposition, with the Kana one for it.
+    Preposition(&'static str),
+    /// Joins subjects, predicates or objects.
+    And,
+    /// Offers the phrase after it in place of the one before.
+    Or,
+    /// "if" or "when": the clause after it is a `la` context.
+    Condition,
+    Pronoun(&'static str),
+    /// A possessive determiner ("my"), with the Kana for its owner.
+    Possessive(&'static str),
+    /// Kana for a word or phrase, with the sense it matched; `None` if it is
+    /// not in the dictionary.
+    Word(String, Option<WordType>),
+}
+
+/// Kana for "today", "tomorrow" and "yesterday", which become a `la`
+/// context: "I work today" → `tenpo ni la mi pali`.
+const TIMES: &[&str] = &["tenpo ni", "tenpo kama", "tenpo pini"];
+
+/// Splits a `la` context from the rest of the sentence: the clause after
+/// "if" or "when", or a time at either end. Returns the ranges of both.
+fn split_context(items: &[Item]) -> (Option<Range<usize>>, Range<usize>) {
+    let len = items.len();
+    let (context, clause) = match items
+        .iter()
+        .position(|item| matches!(item.kind, ItemKind::Condition))
+    {
+        // "I sleep if you eat"
+        Some(i) if i > 0 => (i + 1..len, 0..i),
+        // "if you eat, I sleep"
+        Some(_) => {
+            let rest = &items[1..];
+            let end = match rest.iter().position(|item| item.comma) {
+                Some(comma) => comma + 1,
+                None => next_subject(rest).unwrap_or(rest.len()),
+            };
+            (1..end + 1, end + 1..len)
+        }
+        None => {
+            let is_time = |item: Option<&Item>| {
+                matches!(item.map(|item| &item.kind),
+                    Some(ItemKind::Word(kana, _)) if TIMES.contains(&kana.as_str()))
+            };
+            let kind = |i: usize| items.get(i).map(|item| &item.kind);
+            let is_subject = matches!(kind(1), Some(ItemKind::Copula))
+                && !matches!(kind(2), Some(ItemKind::Pronoun(_)));
+            if is_time(items.first()) && !is_subject {
+                // "today I work"; "today is good" keeps its subject.
+                (0..1, 1..len)
+            } else if is_time(items.last())
+                && !matches!(
+                    kind(len.wrapping_sub(2)),
+                    Some(ItemKind::Copula | ItemKind::Preposition(_))
+                )
+            {
+                // "I work today", but not "I work on today"
+                (len - 1..len, 0..len - 1)
+            } else {
+                return (None, 0..len);
+            }
+        }
+    };
+    match (context.is_empty(), clause.is_empty()) {
+        // "if you eat" alone is just the clause.
+        (false, true) => (None, context),
+        (true, _) => (None, clause),
+        (false, false) => (Some(context), clause),
+    }
+}
+
+/// Without a comma, the context ends where a pronoun starts a new clause:
+/// "if you eat I sleep".
+fn next_subject(items: &[Item]) -> Option<usize> {
+    (2..items.len()).find(|&i| {
+        matches!(items[i].kind, ItemKind::Pronoun(_))
+            && matches!(items[i - 1].kind, ItemKind::Word(..) | ItemKind::Pronoun(_))
+            && matches!(
+                items.get(i + 1).map(|item| &item.kind),
+                Some(ItemKind::Word(..) | ItemKind::Copula | ItemKind::Negation)
+            )
+    })
+}
+
+/// Builds a Kana clause from classified input: the subject, then the verb
+/// with its objects. Modifiers follow the word they modify, whatever the
+/// order of the input language.
+struct ClauseBuilder<'a> {
+    items: &'a [Item],
+    pos: usize,
+    rules: &'static Rules,
+    /// A form of "to be" came before the predicate, so predicates joined to
+    /// it by "and" may be adjectives or nouns too.
+    copula: bool,
+}
+
+impl ClauseBuilder<'_> {
+    fn peek(&self) -> Option<&ItemKind> {
+        self.items.get(self.pos).map(|item| &item.kind)
+    }
+
+    fn clause(&mut self) -> Clause {
+        let mut clause = Clause::default();
+
+        // A verb without a subject, as Polish and Spanish allow.
+        let start = self.pos;
+        self.prefixes();
+        while matches!(self.peek(), Some(ItemKind::Pronoun(_))) && self.is_clitic() {
+            self.pos += 1;
+            self.prefixes();
+        }
+        let implied = match self.items.get(self.pos) {
+            Some(Item {
+                text,
+                kind: ItemKind::Word(_, Some(WordType::Action)),
+                ..
+            }) => self.rules.implied_subject(text),
+            Some(Item {
+                text,
+                kind: ItemKind::Copula,
+                ..
+            }) => self.rules.copula_subject(text),
+            _ => None,
+        };
+        self.pos = start;
+
+        // "is it good?": the verb goes before the subject.
+        let inverted = implied.is_none() && matches!(self.peek(), Some(ItemKind::Copula));
+        // "isn't it good?": the "not" is the predicate's, not the subject's.
+        let mut negated = false;
+        if inverted {
+            self.pos += 1;
+            while matches!(self.peek(), Some(ItemKind::Negation)) {
+                negated = true;
+                self.pos += 1;
+            }
+        }
+
+        let subject = match implied {
+            Some(kana) => Some(Phrase::new(kana)),
+            // Whatever comes first, as a last resort.
+            None => self
+                .noun_phrase()
+                .or_else(|| self.bare_modifiers())
+                .or_else(|| self.word()),
+        };
+        match subject {
+            Some(subject) => clause.subjects.push(subject),
+            // "don't eat": a verb with no subject is the whole clause.
+            None if self.starts_verb() => {}
+            None => return clause,
+        }
+        // "you and I" → `sina en mi`
+        while !clause.subjects.is_empty() && matches!(self.peek(), Some(ItemKind::And)) {
+            let start = self.pos;
+            self.pos += 1;
+            match self.noun_phrase() {
+                Some(subject) => clause.subjects.push(subject),
+                None => {
+                    self.pos = start;
+                    break;
+                }
+            }
+        }
+
+        self.copula = inverted;
+        self.predicates(&mut clause);
+        if let Some(first) = clause.predicates.first_mut() {
+            first.verb.negated |= negated;
+        }
+        clause
+    }
+
+    /// The clause's predicates, up to a new subject or an "or":
+    /// "I eat and sleep" → `mi moku li lape`.
+    fn predicates(&mut self, clause: &mut Clause) {
+        while self.pos < self.items.len() {
+            if !clause.predicates.is_empty()
+                && matches!(self.peek(), Some(ItemKind::And | ItemKind::Or))
+            {
+                // Predicates can't be alternatives, so "or" ends the clause.
+                if matches!(self.peek(), Some(ItemKind::Or)) {
+                    return;
+                }
+                let and = self.pos;
+                self.pos += 1;
+                let start = self.pos;
+                match self.noun_phrase() {
+                    // "I eat and I sleep": the subject isn't repeated.
+                    Some(subject) if clause.subjects[..] == [subject.clone()] => {}
+                    // "I eat and you sleep" ends the clause here.
+                    Some(_) if self.starts_predicate() => {
+                        self.pos = and;
+                        return;
+                    }
+                    _ => self.pos = start,
+                }
+            }
+            let start = self.pos;
+            match self.predicate() {
+                Some(predicate) => clause.predicates.push(predicate),
+                None => self.pos = start + 1,
+            }
+        }
+    }
+
+    /// Whether a subject and its predicate start here.
+    fn starts_clause(&mut self) -> bool {
+        let start = self.pos;
+        let clause = self.noun_phrase().is_some() && self.starts_predicate();
+        self.pos = start;
+        clause
+    }
+
+    /// Whether a verb starts here, after any negations and intensifiers.
+    fn starts_verb(&mut self) -> bool {
+        let start = self.pos;
+        self.prefixes();
+        let verb = matches!(self.peek(), Some(ItemKind::Word(_, Some(WordType::Action))));
+        self.pos = start;
+        verb
+    }
+
+    /// Whether a verb or "to be" starts here, so that the noun phrase
+    /// before it was a new subject.
+    fn starts_predicate(&mut self) -> bool {
+        self.starts_verb() || matches!(self.peek(), Some(ItemKind::Copula))
+    }
+
+    /// A single word of any kind as a phrase.
+    fn word(&mut self) -> Option<Phrase> {
+        let head = match self.peek()? {
+            ItemKind::Word(kana, _) => kana.clone(),
+            ItemKind::Pronoun(kana) | ItemKind::Preposition(kana) => kana.to_string(),
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(Phrase::new(head))
+    }
+
+    /// Negations and intensifiers waiting for the word after them.
+    fn prefixes(&mut self) -> (bool, bool) {
+        let (mut negated, mut intensified) = (false, false);
+        loop {
+            match self.peek() {
+                Some(ItemKind::Negation) => negated = true,
+                Some(ItemKind::Intensifier) => intensified = true,
+                _ => return (negated, intensified),
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// An object pronoun that goes before its verb ("te amo").
+    fn is_clitic(&self) -> bool {
+        self.rules.object_pronouns_first
+            && self
+                .items
+                .get(self.pos)
+                .is_some_and(|item| self.rules.clitics.contains(&item.text.as_str()))
+    }
+
+    /// The verb, with any object pronouns placed before it, then its
+    /// objects and adverbs. After "to be" an adjective or noun is the
+    /// predicate itself: "you are good" → `sina pona`.
+    fn predicate(&mut self) -> Option<Predicate> {
+        let (mut negated, mut intensified) = self.prefixes();
+        if matches!(self.peek(), Some(ItemKind::Copula)) {
+            self.pos += 1;
+            self.copula = true;
+        }
+        let complement = if self.copula {
+            self.noun_phrase().or_else(|| self.bare_modifiers())
+        } else {
+            None
+        };
+
+        let mut objects = Vec::new();
+        let mut verb = match complement {
+            Some(complement) => complement,
+            None => {
+                // "I am eating": "to be" is only a helper.
+                let (more_negated, more_intensified) = self.prefixes();
+                negated |= more_negated;
+                intensified |= more_intensified;
+                while let Some(ItemKind::Pronoun(kana)) = self.peek() {
+                    if !self.is_clitic() {
+                        break;
+                    }
+                    objects.push(Phrase::new(*kana));
+                    self.pos += 1;
+                    let (more_negated, more_intensified) = self.prefixes();
+                    negated |= more_negated;
+                    intensified |= more_intensified;
+                }
+
+                let head = match self.peek() {
+                    Some(ItemKind::Word(kana, _)) => Phrase::new(kana.clone()),
+                    Some(ItemKind::Pronoun(kana)) => Phrase::new(*kana),
+                    // "I am in the house" → `mi lon tomo`.
+                    Some(ItemKind::Preposition(kana)) if self.copula => {
+                        let mut verb = Phrase::new(*kana);
+                        self.pos += 1;
+                        if let Some(object) = self.noun_phrase() {
+                            verb.modifiers.push(Modifier::Word(object.head));
+                            verb.modifiers.extend(object.modifiers);
+                            verb.negated = object.negated;
+                        }
+                        verb.negated |= negated;
+                        return Some(self.predicate_tail(verb, objects));
+                    }
+                    // "I like food": the preposition isn't the verb Kana has.
+                    Some(ItemKind::Preposition(_)) => {
+                        Phrase::new(format!("[{}]", self.items[self.pos].text))
+                    }
+                    // A pronoun with no verb after it was the predicate after all.
+                    _ if !objects.is_empty() => {
+                        let mut verb = objects.remove(0);
+                        verb.negated = negated;
+                        return Some(Predicate {
+                            verb,
+                            objects,
+                            prepositions: Vec::new(),
+                        });
+                    }
+                    _ => return None,
+                };
+                self.pos += 1;
+                head
+            }
+        };
+        verb.negated |= negated;
+        intensify(&mut verb, intensified);
+        Some(self.predicate_tail(verb, objects))
+    }
+
+    /// Objects, prepositional phrases and adverbs after the verb. Only a
+    /// real object takes `e`: "I eat with a fork" → `mi moku kepeken ilo`.
+    fn predicate_tail(&mut self, mut verb: Phrase, mut objects: Vec<Phrase>) -> Predicate {
+        let mut prepositions = Vec::new();
+        loop {
+            if let Some(ItemKind::Preposition(kana)) = self.peek() {
+                let preposition = kana.to_string();
+                self.pos += 1;
+                match self.noun_phrase() {
+                    Some(object) => prepositions.push(Prepositional {
+                        preposition,
+                        object,
+                    }),
+                    // "to" before a verb ("I want to eat") has no Kana.
+                    None if matches!(
+                        self.peek(),
+                        Some(ItemKind::Word(_, Some(WordType::Action)))
+                    ) => {}
+                    None => verb.modifiers.push(Modifier::Word(preposition)),
+                }
+            } else if let Some(object) = self.noun_phrase() {
+                objects.push(object);
+            } else if matches!(self.peek(), Some(ItemKind::And)) {
+                // "food and water" → `e moku e telo`; anything else after
+                // "and", or a noun with its own object ("and drink water"),
+                // starts another predicate.
+                let start = self.pos;
+                self.pos += 1;
+                let object = self.noun_phrase().filter(|_| !objects.is_empty());
+                let after = self.pos;
+                match object {
+                    Some(object) if self.noun_phrase().is_none() && !self.starts_predicate() => {
+                        self.pos = after;
+                        objects.push(object);
+                    }
+                    _ => {
+                        self.pos = start;
+                        break;
+                    }
+                }
+            } else if matches!(self.peek(), Some(ItemKind::Or)) {
+                // "good or bad" → `pona anu ike`; a verb with its own object
+                // ("or drink water") is another predicate, as Kana has no
+                // `anu` between predicates.
+                let start = self.pos;
+                self.pos += 1;
+                let alternative = self.bare_modifiers().or_else(|| self.word());
+                let after = self.pos;
+                if alternative.is_none() || self.noun_phrase().is_some() || self.starts_predicate()
+                {
+                    self.pos = start;
+                    break;
+                }
+                self.pos = after;
+                verb.alternatives.extend(alternative);
+            } else if let Some(group) = self.modifier_group() {
+                verb.modifiers.push(modifier(group));
+            } else {
+                let (negated, intensified) = self.prefixes();
+                if negated || intensified {
+                    verb.negated |= negated;
+                    intensify(&mut verb, intensified);
+                    continue;
+                }
+                let kana = match self.peek() {
+                    Some(ItemKind::Word(kana, _)) => kana.clone(),
+                    Some(ItemKind::Copula) => {
+                        self.pos += 1;
+                        continue;
+                    }
+                    _ => break,
+                };
+                // Anything else modifies the word before it.
+                let word = Modifier::Word(kana);
+                self.pos += 1;
+                match objects.last_mut() {
+                    Some(object) => object.modifiers.push(word),
+                    None => verb.modifiers.push(word),
+                }
+            }
+        }
+
+        Predicate {
+            verb,
+            objects,
+            prepositions,
+        }
+    }
+
+    /// A noun or pronoun with its modifiers, if one starts here: adjectives
+    /// come before the noun or after it as the language has them.
+    fn noun_phrase(&mut self) -> Option<Phrase> {
+        let start = self.pos;
+        let owner = match self.peek() {
+            Some(ItemKind::Possessive(kana)) => {
+                let kana = *kana;
+                self.pos += 1;
+                Some(kana)
+            }
+            _ => None,
+        };
+        let mut before = Vec::new();
+        if self.rules.adjectives_first {
+            while let Some(group) = self.modifier_group() {
+                before.push(group);
+            }
+        }
+
+        let (negated, intensified) = self.prefixes();
+        let mut phrase = match self.peek() {
+            Some(ItemKind::Pronoun(kana)) if !self.is_clitic() => Phrase::new(*kana),
+            Some(ItemKind::Word(kana, None | Some(WordType::Entity))) => Phrase::new(kana.clone()),
+            _ => {
+                self.pos = start;
+                return None;
+            }
+        };
+        self.pos += 1;
+        phrase.negated = negated;
+        intensify(&mut phrase, intensified);
+
+        phrase.modifiers.extend(before.into_iter().map(modifier));
+        if !self.rules.adjectives_first {
+            while let Some(group) = self.modifier_group() {
+                phrase.modifiers.push(modifier(group));
+            }
+        }
+
+        // The owner goes last: "my big house" → `tomo suli mi`,
+        // "the house of big people" → `tomo pi jan suli`.
+        if let Some(owner) = owner {
+            phrase.modifiers.push(modifier(Phrase::new(owner)));
+        }
+        if self
+            .items
+            .get(self.pos)
+            .is_some_and(|item| self.rules.genitives.contains(&item.text.as_str()))
+        {
+            let at = self.pos;
+            self.pos += 1;
+            match self.noun_phrase() {
+                Some(owner) => phrase.modifiers.push(modifier(owner)),
+                None => self.pos = at,
+            }
+        }
+        // "the person's tool" → `ilo jan`
+        if self.items[self.pos - 1].possessive
+            && let Some(mut owned) = self.noun_phrase()
+        {
+            owned.modifiers.push(modifier(phrase));
+            phrase = owned;
+        }
+
+        // "food or water" → `moku anu telo`
+        while matches!(self.peek(), Some(ItemKind::Or)) {
+            let start = self.pos;
+            self.pos += 1;
+            // "food or drink water": a noun with its own object is a verb.
+            let alternative = self.noun_phrase();
+            let after = self.pos;
+            let Some(mut alternative) = alternative.filter(|_| self.noun_phrase().is_none()) else {
+                self.pos = start;
+                break;
+            };
+            self.pos = after;
+            let more = std::mem::take(&mut alternative.alternatives);
+            phrase.alternatives.push(alternative);
+            phrase.alternatives.extend(more);
+        }
+        Some(phrase)
+    }
+
+    /// Adjectives with no noun ("very big"): the last is the head.
+    fn bare_modifiers(&mut self) -> Option<Phrase> {
+        let mut groups = Vec::new();
+        while let Some(group) = self.modifier_group() {
+            groups.push(group);
+        }
+        let mut phrase = groups.pop()?;
+        phrase.modifiers.extend(groups.into_iter().map(modifier));
+        Some(phrase)
+    }
+
+    /// An adjective or number with the negations and intensifiers before
+    /// it: `suli`, or `suli mute` for "very big".
+    fn modifier_group(&mut self) -> Option<Phrase> {
+        let start = self.pos;
+        let (negated, intensified) = self.prefixes();
+        match self.peek() {
+            Some(ItemKind::Word(kana, Some(WordType::Quality | WordType::Number))) => {
+                let mut group = Phrase::new(kana.clone());
+                self.pos += 1;
+                group.negated = negated;
+                intensify(&mut group, intensified);
+                Some(group)
+            }
+            _ => {
+                self.pos = start;
+                None
+            }
+        }
+    }
+}
+
+/// Adds `mute` to a word, unless it already means "much".
+fn intensify(phrase: &mut Phrase, intensified: bool) {
+    if intensified && phrase.head != "mute" {
+        phrase.modifiers.push(Modifier::Word("mute".to_string()));
+    }
+}
+
+/// A modifier group of more than one word takes `pi`.
+fn modifier(group: Phrase) -> Modifier {
+    if group.modifiers.is_empty() && !group.negated && !group.head.contains(' ') {
+        Modifier::Word(group.head)
+    } else {
+        Modifier::Pi(group)
+    }
+}
+
+/// Senses preferred for subjects, objects and nouns after prepositions.
+const NOUN: &[WordType] = &[WordType::Entity, WordType::Number];
+
+/// Senses preferred for words modifying a head.
+const MODIFIER: &[WordType] = &[WordType::Quality, WordType::Number];
+
+/// What a phrase is looked up by in the pronoun tables: its head, or
+/// `mi mute` ("we") and the like; `None` if it has other modifiers.
+fn pronoun_kana(phrase: &Phrase) -> Option<String> {
+    if phrase.negated {
+        return None;
+    }
+    match phrase.modifiers.as_slice() {
+        [] => Some(phrase.head.clone()),
+        [Modifier::Word(word)] if word == "mute" => Some(format!("{} mute", phrase.head)),
+        _ => None,
+    }
+}
+
+/// Punctuation for a translated Kana sentence: the input's own, except
+/// that `se` turns a full stop, or none, into a question mark.
+fn sentence_end(question: bool, terminator: Option<char>) -> char {
+    match terminator {
+        Some('.') | None if question => '?',
+        Some(ch) => ch,
+        None => '.',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_kana(input: &str) -> String {
+        Translator::new().to_kana(input)
+    }
+
+    #[test]
+    fn each_sentence_keeps_its_punctuation() {
+        assert_eq!(to_kana("I eat. You sleep!"), "mi moku. sina lape!");
+        assert_eq!(to_kana("I eat... you sleep"), "mi moku. sina lape");
+        assert_eq!(
+            Translator::new().from_kana("mi moku. sina lape!"),
+            "I eat. you sleep!"
+        );
+    }
+
+    #[test]
+    fn modifiers_follow_their_head() {
+        assert_eq!(to_kana("big house"), "tomo suli");
+        assert_eq!(to_kana("good food is good"), "moku pona li pona");
+    }
+
+    #[test]
+    fn intensified_modifiers_are_grouped_with_pi() {
+        assert_eq!(
+            to_kana("I see a very big house"),
+            "mi lukin e tomo pi suli mute"
+        );
+        assert_eq!(
+            to_kana("the really good person eats"),
+            "jan pi pona mute li moku"
+        );
+    }
+
+    #[test]
+    fn to_be_leaves_a_bare_predicate() {
+        assert_eq!(to_kana("you are good"), "sina pona");
+        assert_eq!(to_kana("the house is big"), "tomo li suli");
+        assert_eq!(Translator::new().from_kana("sina pona"), "you are good.");
+    }
+
+    #[test]
+    fn negated_questions_negate_the_predicate() {
+        assert_eq!(to_kana("isn't it good?"), "se ona li pona ala?");
+        assert_eq!(to_kana("aren't you happy?"), "se sina pilin ala pona?");
+        assert_eq!(to_kana("is not the house big?"), "se tomo li suli ala?");
+    }
+
+    #[test]
+    fn prepositions_follow_the_predicate() {
+        assert_eq!(to_kana("I eat with a tool"), "mi moku kepeken ilo");
+        assert_eq!(to_kana("I am in the house"), "mi lon tomo");
+        assert_eq!(to_kana("I am like you"), "mi sama sina");
+        let translator = Translator::new();
+        assert_eq!(
+            translator.from_kana("mi moku kepeken ilo"),
+            "I eat with tool."
+        );
+        assert_eq!(translator.from_kana("mi tawa tomo mi"), "I go to my house.");
+    }
+
+    #[test]
+    fn a_preposition_is_not_a_verb() {
+        assert_eq!(to_kana("I like food"), "mi [like] e moku");
+        assert_eq!(to_kana("I like food a lot"), "mi [like] mute e moku");
+    }
+
+    #[test]
+    fn and_joins_subjects_predicates_and_objects() {
+        assert_eq!(to_kana("you and I eat"), "sina en mi li moku");
+        assert_eq!(to_kana("I eat and sleep"), "mi moku li lape");
+        assert_eq!(to_kana("I want food and water"), "mi wile e moku e telo");
+        assert_eq!(to_kana("I eat and you sleep"), "mi moku; sina lape");
+    }
+
+    #[test]
+    fn or_is_never_dropped() {
+        assert_eq!(to_kana("I eat or sleep"), "mi moku anu lape");
+        assert_eq!(to_kana("I want food or water"), "mi wile e moku anu telo");
+        assert_eq!(to_kana("I eat or you sleep"), "mi moku; anu sina lape");
+        assert_eq!(
+            to_kana("I eat food or drink water"),
+            "mi moku e moku; anu mi telo e telo"
+        );
+        assert_eq!(
+            Translator::new().from_kana("mi moku; anu sina lape"),
+            "I eat; or you sleep."
+        );
+    }
+
+    #[test]
+    fn conditions_and_times_become_la_contexts() {
+        assert_eq!(to_kana("if you eat, I sleep"), "sina moku la mi lape");
+        assert_eq!(to_kana("when you eat, I sleep"), "sina moku la mi lape");
+        assert_eq!(
+            to_kana("tomorrow I eat food"),
+            "tenpo kama la mi moku e moku"
+        );
+        assert_eq!(to_kana("I eat today"), "tenpo ni la mi moku");
+        let translator = Translator::new();
+        assert_eq!(
+            translator.from_kana("sina moku la mi lape"),
+            "if you eat, I sleep."
+        );
+        assert_eq!(translator.from_kana("tenpo ni la mi moku"), "today, I eat.");
+    }
+
+    #[test]
+    fn owners_follow_what_they_own() {
+        assert_eq!(to_kana("my house"), "tomo mi");
+        assert_eq!(to_kana("the person's tool"), "ilo jan");
+        assert_eq!(to_kana("the house of big people"), "tomo pi jan suli");
+        let translator = Translator::new();
+        assert_eq!(translator.from_kana("tomo mi li suli"), "my house is big.");
+        assert_eq!(
+            translator.from_kana("tomo pi jan suli"),
+            "house of big person."
+        );
+    }
+
+    #[test]
+    fn compounds_are_merged_inside_pi() {
+        let kana = to_kana("my friend's house");
+        assert_eq!(kana, "tomo pi jan pona mi");
+        assert_eq!(Translator::new().from_kana(&kana), "house of my friend.");
+    }
+
+    #[test]
+    fn verbs_are_conjugated_back() {
+        let polish = Translator::with_dictionary(Dictionary::builtin(Lang::Polish));
+        assert_eq!(polish.from_kana("mi wile e moku"), "ja chcę jedzenie.");
+        assert_eq!(polish.from_kana("mi moku anu lape"), "ja jem albo śpię.");
+        let german = Translator::with_dictionary(Dictionary::builtin(Lang::German));
+        assert_eq!(german.from_kana("mi mute li moku"), "wir essen.");
+        assert_eq!(german.from_kana("jan li lape ala"), "Person schläft nicht.");
+        let spanish = Translator::with_dictionary(Dictionary::builtin(Lang::Spanish));
+        assert_eq!(spanish.from_kana("sina en mi li moku"), "tú y yo comemos.");
+        assert_eq!(
+            spanish.from_kana("sina en mi li pona"),
+            "tú y yo somos bueno."
+        );
+    }
+
+    #[test]
+    fn prepositions_of_several_words_need_no_dictionary_phrase() {
+        let dict =
+            Dictionary::from_reader("moku\taction\tjeść\nilo\tentity\tnarzędzie\n".as_bytes())
+                .unwrap()
+                .with_lang(Lang::Polish);
+        assert_eq!(
+            Translator::with_dictionary(dict).to_kana("jem za pomocą narzędzie"),
+            "mi moku kepeken ilo"
+        );
+    }
+
+    #[test]
+    fn only_a_question_takes_se() {
+        assert_eq!(to_kana("I eat. Do you eat?"), "mi moku. se sina moku?");
     }
 }